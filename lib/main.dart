@@ -1,23 +1,49 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'models/settings_provider.dart';
 import 'screens/editor_screen.dart';
+import 'widgets/timeline_theme.dart';
 
 void main() {
   runApp(const ProviderScope(child: MyApp()));
 }
 
-class MyApp extends StatelessWidget {
+class MyApp extends ConsumerWidget {
   const MyApp({super.key});
 
   @override
-  Widget build(BuildContext context) {
+  Widget build(BuildContext context, WidgetRef ref) {
+    final settings = ref.watch(settingsProvider);
+
     return MaterialApp(
       title: 'Dino',
       theme: ThemeData(
-        colorScheme: ColorScheme.fromSeed(seedColor: Colors.deepPurple),
+        colorScheme: ColorScheme.fromSeed(seedColor: Colors.deepPurple)
+            .copyWith(
+              surface: Colors.white,
+              outlineVariant: const Color(0xFFE0E0E0),
+            ),
+        scaffoldBackgroundColor: const Color(0xFFF6F7FB),
+        appBarTheme: const AppBarTheme(
+          backgroundColor: Colors.white,
+          foregroundColor: Colors.black87,
+        ),
+        useMaterial3: true,
+        extensions: [TimelineTheme.light],
+      ),
+      darkTheme: ThemeData(
+        colorScheme: ColorScheme.fromSeed(
+          seedColor: Colors.deepPurple,
+          brightness: Brightness.dark,
+        ),
         useMaterial3: true,
+        extensions: [TimelineTheme.dark],
+      ),
+      themeMode: settings.themeMode,
+      showPerformanceOverlay: settings.showPerformanceOverlay,
+      home: const UnsavedChangesGuard(
+        child: PlaybackShortcuts(child: EditorScreen()),
       ),
-      home: const EditorScreen(),
       debugShowCheckedModeBanner: false,
     );
   }
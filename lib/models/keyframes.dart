@@ -0,0 +1,49 @@
+/// A value animated over a clip's duration. Points are kept sorted by time
+/// and sampled with linear interpolation; before the first or after the last
+/// point the value holds.
+class Keyframes<T> {
+  final List<(Duration, T)> points;
+  final T Function(T a, T b, double t) lerp;
+
+  Keyframes(Iterable<(Duration, T)> points, {required this.lerp})
+    : points = List.unmodifiable(
+        [...points]..sort((a, b) => a.$1.compareTo(b.$1)),
+      ) {
+    assert(this.points.isNotEmpty, 'Keyframes need at least one point');
+  }
+
+  /// A value that never changes.
+  Keyframes.constant(T value, {required this.lerp})
+    : points = List.unmodifiable([(Duration.zero, value)]);
+
+  bool get isAnimated => points.length > 1;
+
+  T valueAt(Duration time) {
+    if (time <= points.first.$1) return points.first.$2;
+    if (time >= points.last.$1) return points.last.$2;
+
+    final next = points.indexWhere((point) => point.$1 > time);
+    final (startTime, startValue) = points[next - 1];
+    final (endTime, endValue) = points[next];
+    final t =
+        (time - startTime).inMicroseconds /
+        (endTime - startTime).inMicroseconds;
+    return lerp(startValue, endValue, t);
+  }
+
+  /// Returns a copy with [value] at [time], replacing any point already
+  /// there.
+  Keyframes<T> withPoint(Duration time, T value) {
+    return Keyframes([
+      for (final point in points)
+        if (point.$1 != time) point,
+      (time, value),
+    ], lerp: lerp);
+  }
+
+  /// Returns a copy without the point at [time]. The last point is kept.
+  Keyframes<T> withoutPoint(Duration time) {
+    final remaining = points.where((point) => point.$1 != time);
+    return remaining.isEmpty ? this : Keyframes(remaining, lerp: lerp);
+  }
+}
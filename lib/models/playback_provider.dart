@@ -0,0 +1,99 @@
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+/// Represents the transport state shared by the playback controls.
+class PlaybackState {
+  /// Master output level, from 0 (silent) to 1 (unity gain).
+  final double masterVolume;
+  final bool audioMuted;
+
+  /// Playhead position on the timeline.
+  final Duration position;
+
+  /// Playback rate, where 1 is real time.
+  final double speed;
+
+  PlaybackState({
+    this.masterVolume = 1.0,
+    this.audioMuted = false,
+    this.position = Duration.zero,
+    this.speed = 1.0,
+  });
+
+  /// Gain the audio output applies to the final mix, independent of any
+  /// per-clip levels.
+  double get outputGain => audioMuted ? 0.0 : masterVolume;
+
+  PlaybackState copyWith({
+    double? masterVolume,
+    bool? audioMuted,
+    Duration? position,
+    double? speed,
+  }) {
+    return PlaybackState(
+      masterVolume: masterVolume ?? this.masterVolume,
+      audioMuted: audioMuted ?? this.audioMuted,
+      position: position ?? this.position,
+      speed: speed ?? this.speed,
+    );
+  }
+}
+
+/// Notifier for managing the playback state.
+class PlaybackNotifier extends StateNotifier<PlaybackState> {
+  PlaybackNotifier() : super(PlaybackState());
+
+  static const minSpeed = 0.1;
+  static const maxSpeed = 8.0;
+
+  /// Speeds offered in the transport and stepped through by [faster] and
+  /// [slower].
+  static const speedPresets = [0.25, 0.5, 1.0, 2.0, 4.0];
+
+  void setMasterVolume(double volume) {
+    state = state.copyWith(masterVolume: volume.clamp(0.0, 1.0));
+  }
+
+  void toggleMute() {
+    state = state.copyWith(audioMuted: !state.audioMuted);
+  }
+
+  /// Sets the playback rate, clamped to [minSpeed]..[maxSpeed]. All speed
+  /// changes go through here so the clamp stays authoritative.
+  void setSpeed(double speed) {
+    state = state.copyWith(speed: speed.clamp(minSpeed, maxSpeed));
+  }
+
+  /// Steps up to the next preset above the current speed.
+  void faster() {
+    setSpeed(
+      speedPresets.firstWhere(
+        (preset) => preset > state.speed,
+        orElse: () => speedPresets.last,
+      ),
+    );
+  }
+
+  /// Steps down to the next preset below the current speed.
+  void slower() {
+    setSpeed(
+      speedPresets.lastWhere(
+        (preset) => preset < state.speed,
+        orElse: () => speedPresets.first,
+      ),
+    );
+  }
+
+  /// Moves the playhead to [position], clamped to `0..max`.
+  void seek(Duration position, {required Duration max}) {
+    final clamped = position > max ? max : position;
+    state = state.copyWith(
+      position: clamped.isNegative ? Duration.zero : clamped,
+    );
+  }
+}
+
+/// Riverpod provider for the playback state.
+final playbackProvider =
+    StateNotifierProvider<PlaybackNotifier, PlaybackState>((ref) {
+      return PlaybackNotifier();
+    });
@@ -1,4 +1,7 @@
+import 'dart:ui' show Color;
+
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'keyframes.dart';
 import 'video_project.dart';
 
 /// Enum for media types.
@@ -12,13 +15,63 @@ class MediaClip {
   final Duration end;
   final MediaType type;
 
+  /// Optional user-facing name, shown instead of the file path.
+  final String? name;
+
+  /// Optional color label for organizing clips on the timeline.
+  final Color? labelColor;
+
+  /// Clips sharing a group id are linked, e.g. a video and its audio, and
+  /// are removed together.
+  final String? groupId;
+
+  /// Opacity from 0 to 1, keyed by time from the clip's start. Null means
+  /// fully opaque.
+  final Keyframes<double>? opacity;
+
   MediaClip({
     required this.id,
     required this.filePath,
     required this.start,
     required this.end,
     required this.type,
+    this.name,
+    this.labelColor,
+    this.groupId,
+    this.opacity,
   });
+
+  String get displayName => name ?? filePath;
+
+  /// Opacity [offset] into the clip.
+  double opacityAt(Duration offset) => opacity?.valueAt(offset) ?? 1.0;
+
+  /// Returns a copy with the given fields replaced. The nullable fields
+  /// can't be cleared by passing null, so each has a `clear` flag instead.
+  MediaClip copyWith({
+    Duration? start,
+    Duration? end,
+    String? name,
+    bool clearName = false,
+    Color? labelColor,
+    bool clearLabelColor = false,
+    String? groupId,
+    bool clearGroupId = false,
+    Keyframes<double>? opacity,
+    bool clearOpacity = false,
+  }) {
+    return MediaClip(
+      id: id,
+      filePath: filePath,
+      start: start ?? this.start,
+      end: end ?? this.end,
+      type: type,
+      name: clearName ? null : name ?? this.name,
+      labelColor: clearLabelColor ? null : labelColor ?? this.labelColor,
+      groupId: clearGroupId ? null : groupId ?? this.groupId,
+      opacity: clearOpacity ? null : opacity ?? this.opacity,
+    );
+  }
 }
 
 /// Represents the state of the video project.
@@ -26,33 +79,162 @@ class ProjectState {
   final String title;
   final List<MediaClip> clips;
 
-  ProjectState({required this.title, required this.clips});
+  /// Whether there are edits since the project was created. There is no
+  /// saving yet, so only [ProjectNotifier.newProject] clears it.
+  final bool isDirty;
 
-  ProjectState copyWith({String? title, List<MediaClip>? clips}) {
-    return ProjectState(title: title ?? this.title, clips: clips ?? this.clips);
+  ProjectState({
+    required this.title,
+    required this.clips,
+    this.isDirty = false,
+  });
+
+  /// Length of the timeline, i.e. the latest clip end.
+  Duration get duration => clips.fold(
+    Duration.zero,
+    (longest, clip) => clip.end > longest ? clip.end : longest,
+  );
+
+  ProjectState copyWith({
+    String? title,
+    List<MediaClip>? clips,
+    bool? isDirty,
+  }) {
+    return ProjectState(
+      title: title ?? this.title,
+      clips: clips ?? this.clips,
+      isDirty: isDirty ?? this.isDirty,
+    );
   }
 }
 
 /// Notifier for managing the project state.
 class ProjectNotifier extends StateNotifier<ProjectState> {
-  ProjectNotifier() : super(ProjectState(title: "Untitled Project", clips: []));
+  ProjectNotifier() : super(_emptyProject());
+
+  static ProjectState _emptyProject() {
+    return ProjectState(title: "Untitled Project", clips: []);
+  }
+
+  /// Shortest clip the timeline will hold.
+  static const minClipDuration = Duration(milliseconds: 100);
 
+  /// Adds [clip], clamping its start to zero and its length to at least
+  /// [minClipDuration]. Audio clips without a positive duration are dropped,
+  /// since there is nothing to play.
   void addClip(MediaClip clip) {
-    state = state.copyWith(clips: [...state.clips, clip]);
+    if (clip.type == MediaType.audio && clip.end <= clip.start) {
+      return;
+    }
+
+    final start = clip.start.isNegative ? Duration.zero : clip.start;
+    var end = clip.end - clip.start + start;
+    if (end - start < minClipDuration) {
+      end = start + minClipDuration;
+    }
+
+    final sanitized = start == clip.start && end == clip.end
+        ? clip
+        : clip.copyWith(start: start, end: end);
+    state = state.copyWith(clips: [...state.clips, sanitized], isDirty: true);
   }
 
+  /// Sets the opacity keyframes of clip [id], or makes it fully opaque
+  /// when [opacity] is null.
+  void setClipOpacity(String id, Keyframes<double>? opacity) {
+    state = state.copyWith(
+      clips: [
+        for (final clip in state.clips)
+          clip.id == id
+              ? clip.copyWith(opacity: opacity, clearOpacity: opacity == null)
+              : clip,
+      ],
+      isDirty: true,
+    );
+  }
+
+  /// Removes the clip with [id] along with any clips linked to it.
   void removeClip(String id) {
+    final groupId = _groupOf(id);
+    state = state.copyWith(
+      clips: state.clips
+          .where(
+            (clip) =>
+                clip.id != id && (groupId == null || clip.groupId != groupId),
+          )
+          .toList(),
+      isDirty: true,
+    );
+  }
+
+  String? _groupOf(String id) {
+    return state.clips.where((clip) => clip.id == id).firstOrNull?.groupId;
+  }
+
+  /// Links the clips in [ids] so they are edited together. Groups any of
+  /// them already belong to are merged in, keeping the first existing group
+  /// id, or else the id of the first clip.
+  void linkClips(List<String> ids) {
+    if (ids.length < 2) return;
+    final existing = {
+      for (final id in ids) ?_groupOf(id),
+    };
+    final groupId = existing.firstOrNull ?? ids.first;
     state = state.copyWith(
-      clips: state.clips.where((clip) => clip.id != id).toList(),
+      clips: [
+        for (final clip in state.clips)
+          ids.contains(clip.id) || existing.contains(clip.groupId)
+              ? clip.copyWith(groupId: groupId)
+              : clip,
+      ],
+      isDirty: true,
+    );
+  }
+
+  /// Unlinks every clip grouped with [id], including itself.
+  void unlinkClip(String id) {
+    final groupId = _groupOf(id);
+    if (groupId == null) return;
+    state = state.copyWith(
+      clips: [
+        for (final clip in state.clips)
+          clip.groupId == groupId ? clip.copyWith(clearGroupId: true) : clip,
+      ],
+      isDirty: true,
+    );
+  }
+
+  /// Sets the name and label color of clip [id]. Passing null clears that
+  /// part.
+  void setClipLabel(String id, {String? name, Color? labelColor}) {
+    state = state.copyWith(
+      clips: [
+        for (final clip in state.clips)
+          clip.id == id
+              ? clip.copyWith(
+                  name: name,
+                  clearName: name == null,
+                  labelColor: labelColor,
+                  clearLabelColor: labelColor == null,
+                )
+              : clip,
+      ],
+      isDirty: true,
     );
   }
 
   void clearClips() {
-    state = state.copyWith(clips: []);
+    state = state.copyWith(clips: [], isDirty: true);
   }
 
   void setTitle(String title) {
-    state = state.copyWith(title: title);
+    if (title == state.title) return;
+    state = state.copyWith(title: title, isDirty: true);
+  }
+
+  /// Starts over with an empty, unmodified project.
+  void newProject() {
+    state = _emptyProject();
   }
 }
 
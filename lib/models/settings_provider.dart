@@ -0,0 +1,56 @@
+import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+/// Editor-wide preferences that are not part of a project.
+class EditorSettings {
+  final ThemeMode themeMode;
+  final bool showStatusBar;
+
+  /// Draws Flutter's raster/UI frame-time graphs over the app. Off by default;
+  /// meant for diagnosing stutter.
+  final bool showPerformanceOverlay;
+
+  EditorSettings({
+    this.themeMode = ThemeMode.system,
+    this.showStatusBar = true,
+    this.showPerformanceOverlay = false,
+  });
+
+  EditorSettings copyWith({
+    ThemeMode? themeMode,
+    bool? showStatusBar,
+    bool? showPerformanceOverlay,
+  }) {
+    return EditorSettings(
+      themeMode: themeMode ?? this.themeMode,
+      showStatusBar: showStatusBar ?? this.showStatusBar,
+      showPerformanceOverlay:
+          showPerformanceOverlay ?? this.showPerformanceOverlay,
+    );
+  }
+}
+
+/// Notifier for managing the editor settings.
+class SettingsNotifier extends StateNotifier<EditorSettings> {
+  SettingsNotifier() : super(EditorSettings());
+
+  void setThemeMode(ThemeMode mode) {
+    state = state.copyWith(themeMode: mode);
+  }
+
+  void toggleStatusBar() {
+    state = state.copyWith(showStatusBar: !state.showStatusBar);
+  }
+
+  void togglePerformanceOverlay() {
+    state = state.copyWith(
+      showPerformanceOverlay: !state.showPerformanceOverlay,
+    );
+  }
+}
+
+/// Riverpod provider for the editor settings.
+final settingsProvider =
+    StateNotifierProvider<SettingsNotifier, EditorSettings>((ref) {
+      return SettingsNotifier();
+    });
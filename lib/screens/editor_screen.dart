@@ -1,35 +1,85 @@
+import 'dart:ui' show AppExitResponse;
+
 import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import '../models/playback_provider.dart';
+import '../models/project_provider.dart';
+import '../models/settings_provider.dart';
 import '../widgets/video_preview.dart';
 import '../widgets/timeline.dart';
 import '../widgets/controls.dart';
 import '../widgets/side_panel.dart';
+import '../widgets/status_bar.dart';
 
-class EditorScreen extends StatelessWidget {
+class EditorScreen extends ConsumerWidget {
   const EditorScreen({super.key});
 
   @override
-  Widget build(BuildContext context) {
+  Widget build(BuildContext context, WidgetRef ref) {
+    final isDark = Theme.of(context).brightness == Brightness.dark;
+    final colors = Theme.of(context).colorScheme;
+    final settings = ref.watch(settingsProvider);
+
     // Main layout: Side panel | Main area (Video preview + Timeline + Controls)
     return Scaffold(
-      backgroundColor: const Color(0xFFF6F7FB),
       appBar: AppBar(
         title: const Text(
           'Video Editor',
           style: TextStyle(fontWeight: FontWeight.w600, letterSpacing: 0.5),
         ),
         elevation: 0.5,
-        backgroundColor: Colors.white,
-        foregroundColor: Colors.black87,
         centerTitle: false,
+        actions: [
+          IconButton(
+            tooltip: 'New project',
+            icon: const Icon(Icons.note_add_outlined),
+            onPressed: () async {
+              if (await confirmDiscardChanges(context, ref)) {
+                ref.read(projectProvider.notifier).newProject();
+                ref.invalidate(playbackProvider);
+              }
+            },
+          ),
+          IconButton(
+            tooltip: settings.showStatusBar
+                ? 'Hide status bar'
+                : 'Show status bar',
+            icon: const Icon(Icons.info_outline),
+            isSelected: settings.showStatusBar,
+            onPressed: ref.read(settingsProvider.notifier).toggleStatusBar,
+          ),
+          IconButton(
+            tooltip: 'Performance overlay',
+            icon: const Icon(Icons.speed),
+            isSelected: settings.showPerformanceOverlay,
+            onPressed: ref
+                .read(settingsProvider.notifier)
+                .togglePerformanceOverlay,
+          ),
+          PopupMenuButton<ThemeMode>(
+            tooltip: 'Theme',
+            icon: Icon(isDark ? Icons.dark_mode : Icons.light_mode),
+            initialValue: settings.themeMode,
+            onSelected: ref.read(settingsProvider.notifier).setThemeMode,
+            itemBuilder: (context) => const [
+              PopupMenuItem(value: ThemeMode.system, child: Text('System')),
+              PopupMenuItem(value: ThemeMode.light, child: Text('Light')),
+              PopupMenuItem(value: ThemeMode.dark, child: Text('Dark')),
+            ],
+          ),
+          const SizedBox(width: 8),
+        ],
       ),
+      bottomNavigationBar: settings.showStatusBar ? const StatusBar() : null,
       body: Row(
         crossAxisAlignment: CrossAxisAlignment.stretch,
         children: [
           // Side Panel
           Container(
-            decoration: const BoxDecoration(
-              color: Colors.white,
-              border: Border(right: BorderSide(color: Color(0xFFE0E0E0))),
+            decoration: BoxDecoration(
+              color: colors.surface,
+              border: Border(right: BorderSide(color: colors.outlineVariant)),
             ),
             width: 220,
             child: Column(
@@ -38,15 +88,15 @@ class EditorScreen extends StatelessWidget {
                 Icon(
                   Icons.video_library_outlined,
                   size: 40,
-                  color: Colors.deepPurple.shade200,
+                  color: colors.primary.withOpacity(0.5),
                 ),
                 const SizedBox(height: 12),
-                const Text(
+                Text(
                   'Project',
                   style: TextStyle(
                     fontWeight: FontWeight.w600,
                     fontSize: 18,
-                    color: Colors.black87,
+                    color: colors.onSurface,
                   ),
                 ),
                 const SizedBox(height: 8),
@@ -58,18 +108,21 @@ class EditorScreen extends StatelessWidget {
                     label: const Text('Import Media'),
                     style: OutlinedButton.styleFrom(
                       minimumSize: const Size.fromHeight(36),
-                      foregroundColor: Colors.deepPurple,
-                      side: const BorderSide(color: Color(0xFFD1C4E9)),
+                      foregroundColor: colors.primary,
+                      side: BorderSide(color: colors.primaryContainer),
                       textStyle: const TextStyle(fontWeight: FontWeight.w500),
                     ),
                   ),
                 ),
                 const Spacer(),
-                const Padding(
-                  padding: EdgeInsets.only(bottom: 16.0),
+                Padding(
+                  padding: const EdgeInsets.only(bottom: 16.0),
                   child: Text(
                     'No clips yet',
-                    style: TextStyle(color: Colors.grey, fontSize: 13),
+                    style: TextStyle(
+                      color: colors.onSurfaceVariant,
+                      fontSize: 13,
+                    ),
                   ),
                 ),
               ],
@@ -103,14 +156,14 @@ class EditorScreen extends StatelessWidget {
                             children: [
                               Icon(
                                 Icons.info_outline,
-                                color: Colors.deepPurple.shade100,
+                                color: colors.primary.withOpacity(0.3),
                                 size: 20,
                               ),
                               const SizedBox(width: 8),
                               Text(
                                 'No media loaded. Import video or audio to get started.',
                                 style: TextStyle(
-                                  color: Colors.grey[500],
+                                  color: colors.onSurfaceVariant,
                                   fontSize: 15,
                                   fontStyle: FontStyle.italic,
                                 ),
@@ -137,13 +190,16 @@ class EditorScreen extends StatelessWidget {
                       child: Column(
                         crossAxisAlignment: CrossAxisAlignment.start,
                         children: [
-                          const Padding(
-                            padding: EdgeInsets.only(left: 8.0, bottom: 2),
+                          Padding(
+                            padding: const EdgeInsets.only(
+                              left: 8.0,
+                              bottom: 2,
+                            ),
                             child: Text(
                               'Timeline',
                               style: TextStyle(
                                 fontWeight: FontWeight.w500,
-                                color: Colors.black54,
+                                color: colors.onSurfaceVariant,
                                 fontSize: 15,
                               ),
                             ),
@@ -178,3 +234,113 @@ class EditorScreen extends StatelessWidget {
     );
   }
 }
+
+/// Asks before throwing away unsaved edits. Returns true right away when the
+/// project has none.
+Future<bool> confirmDiscardChanges(BuildContext context, WidgetRef ref) async {
+  if (!ref.read(projectProvider).isDirty) return true;
+
+  final discard = await showDialog<bool>(
+    context: context,
+    builder: (context) => AlertDialog(
+      title: const Text('Discard unsaved changes?'),
+      content: const Text('Edits to the current project will be lost.'),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(false),
+          child: const Text('Cancel'),
+        ),
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(true),
+          child: const Text('Discard'),
+        ),
+      ],
+    ),
+  );
+  return discard ?? false;
+}
+
+/// Holds off closing the app while the project has unsaved edits, asking the
+/// user first.
+class UnsavedChangesGuard extends ConsumerStatefulWidget {
+  final Widget child;
+
+  const UnsavedChangesGuard({super.key, required this.child});
+
+  @override
+  ConsumerState<UnsavedChangesGuard> createState() =>
+      _UnsavedChangesGuardState();
+}
+
+class _UnsavedChangesGuardState extends ConsumerState<UnsavedChangesGuard> {
+  late final AppLifecycleListener _listener;
+
+  @override
+  void initState() {
+    super.initState();
+    _listener = AppLifecycleListener(onExitRequested: _onExitRequested);
+  }
+
+  @override
+  void dispose() {
+    _listener.dispose();
+    super.dispose();
+  }
+
+  Future<AppExitResponse> _onExitRequested() async {
+    final discard = await confirmDiscardChanges(context, ref);
+    return discard ? AppExitResponse.exit : AppExitResponse.cancel;
+  }
+
+  @override
+  Widget build(BuildContext context) => widget.child;
+}
+
+/// Editor-wide playback keys: `+`/`-` step through the speed presets. Keys
+/// are left alone while a text field has focus so they can still be typed,
+/// and while a dialog or menu is open over the editor.
+class PlaybackShortcuts extends ConsumerStatefulWidget {
+  final Widget child;
+
+  const PlaybackShortcuts({super.key, required this.child});
+
+  @override
+  ConsumerState<PlaybackShortcuts> createState() => _PlaybackShortcutsState();
+}
+
+class _PlaybackShortcutsState extends ConsumerState<PlaybackShortcuts> {
+  @override
+  void initState() {
+    super.initState();
+    HardwareKeyboard.instance.addHandler(_onKey);
+  }
+
+  @override
+  void dispose() {
+    HardwareKeyboard.instance.removeHandler(_onKey);
+    super.dispose();
+  }
+
+  bool _onKey(KeyEvent event) {
+    if (event is KeyUpEvent) return false;
+    if (ModalRoute.of(context)?.isCurrent != true) return false;
+    final focused = FocusManager.instance.primaryFocus?.context;
+    if (focused?.findAncestorStateOfType<EditableTextState>() != null) {
+      return false;
+    }
+
+    final notifier = ref.read(playbackProvider.notifier);
+    switch (event.character) {
+      case '+':
+        notifier.faster();
+        return true;
+      case '-':
+        notifier.slower();
+        return true;
+    }
+    return false;
+  }
+
+  @override
+  Widget build(BuildContext context) => widget.child;
+}
@@ -12,5 +12,52 @@ class VideoUtils {
     return "$minutes:$seconds";
   }
 
+  /// Frame rate assumed for timecodes until clips carry their own.
+  static const double defaultFrameRate = 30;
+
+  /// Formats a duration as an `HH:MM:SS:FF` timecode at [fps].
+  static String formatTimecode(Duration duration, double fps) {
+    String twoDigits(int n) => n.toString().padLeft(2, '0');
+    const perSecond = Duration.microsecondsPerSecond;
+    final frames = (duration.inMicroseconds % perSecond) * fps ~/ perSecond;
+    final hours = twoDigits(duration.inHours);
+    final minutes = twoDigits(duration.inMinutes.remainder(60));
+    final seconds = twoDigits(duration.inSeconds.remainder(60));
+    return "$hours:$minutes:$seconds:${twoDigits(frames)}";
+  }
+
+  /// Parses an `HH:MM:SS:FF` timecode at [fps], `[HH:]MM:SS`, or plain
+  /// seconds such as `12.5`. Returns null for malformed or negative input.
+  static Duration? parseTimecode(String text, double fps) {
+    final trimmed = text.trim();
+    if (!trimmed.contains(':')) {
+      final seconds = double.tryParse(trimmed);
+      if (seconds == null || seconds.isNegative || !seconds.isFinite) {
+        return null;
+      }
+      return Duration(microseconds: (seconds * 1e6).round());
+    }
+
+    final parsed = trimmed.split(':').map(int.tryParse).toList();
+    if (parsed.length > 4 || parsed.any((p) => p == null || p < 0)) {
+      return null;
+    }
+    final parts = parsed.cast<int>();
+    final frames = parts.length == 4 ? parts.removeLast() : 0;
+    final hours = parts.length == 3 ? parts.first : 0;
+    final minutes = parts[parts.length - 2];
+    final seconds = parts.last;
+    if (seconds >= 60 || minutes >= 60 || frames >= fps.ceil()) return null;
+
+    // Round up so the result formats back to the same frame.
+    final frameMicros = (frames * Duration.microsecondsPerSecond / fps).ceil();
+    return Duration(
+      hours: hours,
+      minutes: minutes,
+      seconds: seconds,
+      microseconds: frameMicros,
+    );
+  }
+
   // Add more video-related utility methods as needed.
 }
@@ -1,13 +1,26 @@
 import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import '../models/playback_provider.dart';
+import '../models/project_provider.dart';
+import '../utils/video_utils.dart';
 
-class Controls extends StatelessWidget {
+class Controls extends ConsumerWidget {
   const Controls({super.key});
 
   @override
-  Widget build(BuildContext context) {
-    final Color iconColor = Colors.grey.shade800;
-    final Color iconActive = Colors.deepPurpleAccent.shade100;
-    final Color sliderBg = Colors.grey.shade200;
+  Widget build(BuildContext context, WidgetRef ref) {
+    final playback = ref.watch(playbackProvider);
+    final duration = ref.watch(projectProvider).duration;
+    final progress = duration > Duration.zero
+        ? (playback.position.inMicroseconds / duration.inMicroseconds).clamp(
+            0.0,
+            1.0,
+          )
+        : 0.0;
+    final colors = Theme.of(context).colorScheme;
+    final Color iconColor = colors.onSurface;
+    final Color iconActive = colors.primary;
+    final Color sliderBg = colors.surfaceContainerHighest;
 
     return Container(
       padding: const EdgeInsets.symmetric(vertical: 8, horizontal: 8),
@@ -60,39 +73,240 @@ class Controls extends StatelessWidget {
                     enabledThumbRadius: 7,
                   ),
                   overlayShape: SliderComponentShape.noOverlay,
-                  activeTrackColor: Colors.deepPurpleAccent.shade100,
-                  inactiveTrackColor: Colors.grey.shade400,
-                  thumbColor: Colors.deepPurpleAccent.shade100,
+                  activeTrackColor: iconActive,
+                  inactiveTrackColor: colors.outline,
+                  thumbColor: iconActive,
                 ),
                 child: Slider(
-                  value: 0,
-                  min: 0,
-                  max: 100,
-                  onChanged: (double value) {},
+                  value: progress,
+                  onChanged: (double value) {
+                    ref
+                        .read(playbackProvider.notifier)
+                        .seek(duration * value, max: duration);
+                  },
                 ),
               ),
             ),
           ),
           const SizedBox(width: 8),
           ConstrainedBox(
-            constraints: const BoxConstraints(minWidth: 40, maxWidth: 48),
+            constraints: const BoxConstraints(minWidth: 84, maxWidth: 96),
             child: Text(
-              "00:00",
+              VideoUtils.formatTimecode(
+                playback.position,
+                VideoUtils.defaultFrameRate,
+              ),
               overflow: TextOverflow.fade,
               softWrap: false,
               style: TextStyle(
-                color: Colors.grey.shade600,
+                color: colors.onSurfaceVariant,
                 fontSize: 13,
                 fontFeatures: const [FontFeature.tabularFigures()],
               ),
             ),
           ),
+          const SizedBox(width: 8),
+          _TimecodeField(
+            onSubmitted: (position) => ref
+                .read(playbackProvider.notifier)
+                .seek(position, max: duration),
+          ),
+          const SizedBox(width: 4),
+          PopupMenuButton<Object>(
+            tooltip: 'Playback speed (+/-)',
+            initialValue: playback.speed,
+            onSelected: (choice) async {
+              final notifier = ref.read(playbackProvider.notifier);
+              if (choice is double) {
+                notifier.setSpeed(choice);
+              } else if (choice == 'custom') {
+                final speed = await _promptSpeed(context, playback.speed);
+                if (speed != null) notifier.setSpeed(speed);
+              }
+            },
+            itemBuilder: (context) => [
+              for (final speed in PlaybackNotifier.speedPresets)
+                PopupMenuItem(value: speed, child: Text(_formatSpeed(speed))),
+              const PopupMenuDivider(),
+              const PopupMenuItem(value: 'custom', child: Text('Custom…')),
+            ],
+            child: Padding(
+              padding: const EdgeInsets.symmetric(horizontal: 6),
+              child: Text(
+                _formatSpeed(playback.speed),
+                style: TextStyle(
+                  color: iconColor,
+                  fontSize: 13,
+                  fontWeight: FontWeight.w500,
+                ),
+              ),
+            ),
+          ),
+          const SizedBox(width: 12),
+          _FlatIconButton(
+            icon: playback.audioMuted || playback.masterVolume == 0
+                ? Icons.volume_off_rounded
+                : Icons.volume_up_rounded,
+            tooltip: playback.audioMuted ? 'Unmute' : 'Mute',
+            color: iconColor,
+            onTap: () => ref.read(playbackProvider.notifier).toggleMute(),
+          ),
+          SizedBox(
+            width: 96,
+            child: SliderTheme(
+              data: SliderTheme.of(context).copyWith(
+                trackHeight: 3,
+                thumbShape: const RoundSliderThumbShape(enabledThumbRadius: 6),
+                overlayShape: SliderComponentShape.noOverlay,
+                activeTrackColor: iconActive,
+                inactiveTrackColor: colors.outline,
+                thumbColor: iconActive,
+              ),
+              child: Slider(
+                value: playback.audioMuted ? 0 : playback.masterVolume,
+                onChanged: (double value) {
+                  final notifier = ref.read(playbackProvider.notifier);
+                  notifier.setMasterVolume(value);
+                  if (playback.audioMuted) notifier.toggleMute();
+                },
+              ),
+            ),
+          ),
         ],
       ),
     );
   }
 }
 
+String _formatSpeed(double speed) {
+  final value = speed == speed.roundToDouble()
+      ? speed.toInt().toString()
+      : speed.toString();
+  return '${value}x';
+}
+
+/// Asks for a playback speed such as `1.5` or `1.5x`. Returns null on cancel
+/// or unparseable input.
+Future<double?> _promptSpeed(BuildContext context, double current) {
+  return showDialog<double>(
+    context: context,
+    builder: (context) => _SpeedDialog(current: current),
+  );
+}
+
+class _SpeedDialog extends StatefulWidget {
+  final double current;
+
+  const _SpeedDialog({required this.current});
+
+  @override
+  State<_SpeedDialog> createState() => _SpeedDialogState();
+}
+
+class _SpeedDialogState extends State<_SpeedDialog> {
+  late final TextEditingController _controller = TextEditingController(
+    text: _formatSpeed(widget.current),
+  );
+
+  @override
+  void dispose() {
+    _controller.dispose();
+    super.dispose();
+  }
+
+  double? _parse(String text) =>
+      double.tryParse(text.trim().replaceFirst(RegExp(r'x$'), ''));
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: const Text('Playback speed'),
+      content: TextField(
+        controller: _controller,
+        autofocus: true,
+        decoration: InputDecoration(
+          helperText:
+              '${PlaybackNotifier.minSpeed}x to '
+              '${_formatSpeed(PlaybackNotifier.maxSpeed)}',
+        ),
+        onSubmitted: (value) => Navigator.of(context).pop(_parse(value)),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(_parse(_controller.text)),
+          child: const Text('Set'),
+        ),
+      ],
+    );
+  }
+}
+
+/// Small field for jumping the playhead to a typed timecode or seconds value.
+class _TimecodeField extends StatefulWidget {
+  final ValueChanged<Duration> onSubmitted;
+
+  const _TimecodeField({required this.onSubmitted});
+
+  @override
+  State<_TimecodeField> createState() => _TimecodeFieldState();
+}
+
+class _TimecodeFieldState extends State<_TimecodeField> {
+  final _controller = TextEditingController();
+  bool _invalid = false;
+
+  @override
+  void dispose() {
+    _controller.dispose();
+    super.dispose();
+  }
+
+  void _submit(String text) {
+    final position = VideoUtils.parseTimecode(
+      text,
+      VideoUtils.defaultFrameRate,
+    );
+    setState(() => _invalid = position == null);
+    if (position != null) {
+      widget.onSubmitted(position);
+      _controller.clear();
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return SizedBox(
+      width: 104,
+      child: Tooltip(
+        message: 'Go to timecode (HH:MM:SS:FF or seconds)',
+        child: TextField(
+          controller: _controller,
+          onSubmitted: _submit,
+          style: const TextStyle(
+            fontSize: 13,
+            fontFeatures: [FontFeature.tabularFigures()],
+          ),
+          decoration: InputDecoration(
+            isDense: true,
+            hintText: '00:00:00:00',
+            errorText: _invalid ? '' : null,
+            errorStyle: const TextStyle(height: 0),
+            contentPadding: const EdgeInsets.symmetric(
+              horizontal: 8,
+              vertical: 8,
+            ),
+            border: const OutlineInputBorder(),
+          ),
+        ),
+      ),
+    );
+  }
+}
+
 class _FlatIconButton extends StatefulWidget {
   final IconData icon;
   final String tooltip;
@@ -0,0 +1,46 @@
+import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import '../models/project_provider.dart';
+import '../utils/video_utils.dart';
+
+/// Compact bar summarizing the project: timeline length and clip counts.
+class StatusBar extends ConsumerWidget {
+  const StatusBar({super.key});
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final project = ref.watch(projectProvider);
+    final colors = Theme.of(context).colorScheme;
+    final videoCount = project.clips
+        .where((clip) => clip.type == MediaType.video)
+        .length;
+    final audioCount = project.clips.length - videoCount;
+    final duration = VideoUtils.formatTimecode(
+      project.duration,
+      VideoUtils.defaultFrameRate,
+    );
+    final style = TextStyle(color: colors.onSurfaceVariant, fontSize: 12);
+
+    return Container(
+      height: 26,
+      padding: const EdgeInsets.symmetric(horizontal: 12),
+      decoration: BoxDecoration(
+        color: colors.surfaceContainerHighest,
+        border: Border(top: BorderSide(color: colors.outlineVariant)),
+      ),
+      child: Row(
+        children: [
+          Text(project.title, style: style),
+          const Spacer(),
+          Text('Duration $duration', style: style),
+          const SizedBox(width: 16),
+          Text(
+            '${project.clips.length} clips '
+            '($videoCount video, $audioCount audio)',
+            style: style,
+          ),
+        ],
+      ),
+    );
+  }
+}
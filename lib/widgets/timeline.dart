@@ -1,6 +1,8 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import '../models/project_provider.dart';
+import '../utils/video_utils.dart';
+import 'timeline_theme.dart';
 
 class Timeline extends ConsumerWidget {
   const Timeline({super.key});
@@ -8,6 +10,7 @@ class Timeline extends ConsumerWidget {
   @override
   Widget build(BuildContext context, WidgetRef ref) {
     final clips = ref.watch(projectProvider).clips;
+    final theme = TimelineTheme.of(context);
 
     return SizedBox(
       height: 56,
@@ -18,12 +21,12 @@ class Timeline extends ConsumerWidget {
             padding: const EdgeInsets.only(left: 4, bottom: 2),
             child: Row(
               children: [
-                Icon(Icons.timeline, size: 18, color: Colors.grey[400]),
+                Icon(Icons.timeline, size: 18, color: theme.mutedForeground),
                 const SizedBox(width: 6),
                 Text(
                   'Timeline',
                   style: TextStyle(
-                    color: Colors.grey[700],
+                    color: theme.labelForeground,
                     fontWeight: FontWeight.w500,
                     fontSize: 13.5,
                   ),
@@ -35,8 +38,8 @@ class Timeline extends ConsumerWidget {
             child: Container(
               margin: const EdgeInsets.only(top: 2),
               decoration: BoxDecoration(
-                color: Colors.white,
-                border: Border.all(color: const Color(0xFFE5E7EB), width: 1),
+                color: theme.background,
+                border: Border.all(color: theme.border, width: 1),
                 borderRadius: BorderRadius.circular(6),
               ),
               child: clips.isEmpty
@@ -46,14 +49,14 @@ class Timeline extends ConsumerWidget {
                         children: [
                           Icon(
                             Icons.video_file_outlined,
-                            color: Colors.grey[400],
+                            color: theme.mutedForeground,
                             size: 22,
                           ),
                           const SizedBox(width: 8),
                           Text(
                             'No clips in timeline',
                             style: TextStyle(
-                              color: Colors.grey[400],
+                              color: theme.mutedForeground,
                               fontSize: 14,
                               fontStyle: FontStyle.italic,
                             ),
@@ -69,54 +72,368 @@ class Timeline extends ConsumerWidget {
                       ),
                       itemCount: clips.length,
                       separatorBuilder: (_, __) => const SizedBox(width: 12),
-                      itemBuilder: (context, i) {
-                        final clip = clips[i];
-                        return Container(
-                          padding: const EdgeInsets.symmetric(
-                            horizontal: 16,
-                            vertical: 8,
-                          ),
-                          decoration: BoxDecoration(
-                            color: clip.type == MediaType.video
-                                ? Colors.deepPurple.shade50
-                                : Colors.teal.shade50,
-                            borderRadius: BorderRadius.circular(6),
-                            border: Border.all(
-                              color: clip.type == MediaType.video
-                                  ? Colors.deepPurple.shade100
-                                  : Colors.teal.shade100,
-                              width: 1.5,
-                            ),
-                          ),
-                          child: Row(
-                            children: [
-                              Icon(
-                                clip.type == MediaType.video
-                                    ? Icons.video_file
-                                    : Icons.audiotrack,
-                                color: clip.type == MediaType.video
-                                    ? Colors.deepPurple
-                                    : Colors.teal,
-                                size: 20,
-                              ),
-                              const SizedBox(width: 8),
-                              Text(
-                                clip.filePath,
-                                style: TextStyle(
-                                  color: Colors.grey[800],
-                                  fontWeight: FontWeight.w500,
-                                  fontSize: 13,
-                                ),
-                              ),
-                            ],
-                          ),
-                        );
-                      },
+                      itemBuilder: (context, i) => _ClipChip(clip: clips[i]),
+                    ),
+            ),
+          ),
+        ],
+      ),
+    );
+  }
+}
+
+class _ClipChip extends ConsumerWidget {
+  final MediaClip clip;
+
+  const _ClipChip({required this.clip});
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final isVideo = clip.type == MediaType.video;
+    final theme = TimelineTheme.of(context);
+    const fps = VideoUtils.defaultFrameRate;
+
+    return Tooltip(
+      message:
+          '${clip.filePath}\n'
+          'In ${VideoUtils.formatTimecode(clip.start, fps)}  '
+          'Out ${VideoUtils.formatTimecode(clip.end, fps)}',
+      waitDuration: const Duration(milliseconds: 500),
+      child: GestureDetector(
+        onSecondaryTapUp: (details) =>
+            _showLabelMenu(context, ref, clip, details.globalPosition),
+        onLongPressStart: (details) =>
+            _showLabelMenu(context, ref, clip, details.globalPosition),
+        child: Container(
+          padding: const EdgeInsets.symmetric(horizontal: 16, vertical: 8),
+          decoration: BoxDecoration(
+            color: isVideo ? theme.videoFill : theme.audioFill,
+            borderRadius: BorderRadius.circular(6),
+            border: Border.all(
+              color: isVideo ? theme.videoBorder : theme.audioBorder,
+              width: 1.5,
+            ),
+          ),
+          child: Row(
+            children: [
+              if (clip.labelColor != null) ...[
+                Container(
+                  width: 4,
+                  height: 20,
+                  decoration: BoxDecoration(
+                    color: clip.labelColor,
+                    borderRadius: BorderRadius.circular(2),
+                  ),
+                ),
+                const SizedBox(width: 8),
+              ],
+              Icon(
+                isVideo ? Icons.video_file : Icons.audiotrack,
+                color: isVideo ? theme.videoAccent : theme.audioAccent,
+                size: 20,
+              ),
+              const SizedBox(width: 8),
+              Text(
+                clip.displayName,
+                style: TextStyle(
+                  color: theme.clipText,
+                  fontWeight: FontWeight.w500,
+                  fontSize: 13,
+                ),
+              ),
+            ],
+          ),
+        ),
+      ),
+    );
+  }
+}
+
+/// Colors offered when labeling a clip.
+const _labelColors = <(String, Color)>[
+  ('Red', Color(0xFFEF4444)),
+  ('Orange', Color(0xFFF59E0B)),
+  ('Green', Color(0xFF10B981)),
+  ('Blue', Color(0xFF3B82F6)),
+  ('Purple', Color(0xFF8B5CF6)),
+  ('Pink', Color(0xFFEC4899)),
+];
+
+Future<void> _showLabelMenu(
+  BuildContext context,
+  WidgetRef ref,
+  MediaClip clip,
+  Offset position,
+) async {
+  final notifier = ref.read(projectProvider.notifier);
+  final linkable = ref
+      .read(projectProvider)
+      .clips
+      .where(
+        (other) =>
+            other.id != clip.id &&
+            (clip.groupId == null || other.groupId != clip.groupId),
+      )
+      .toList();
+  final overlay = Overlay.of(context).context.findRenderObject() as RenderBox;
+  final choice = await showMenu<Object>(
+    context: context,
+    position: RelativeRect.fromRect(
+      position & const Size(1, 1),
+      Offset.zero & overlay.size,
+    ),
+    items: [
+      const PopupMenuItem(value: 'properties', child: Text('Properties…')),
+      const PopupMenuItem(value: 'rename', child: Text('Rename…')),
+      if (linkable.isNotEmpty)
+        const PopupMenuItem(value: 'link', child: Text('Link with…')),
+      if (clip.groupId != null)
+        const PopupMenuItem(value: 'unlink', child: Text('Unlink')),
+      const PopupMenuDivider(),
+      for (final (name, color) in _labelColors)
+        PopupMenuItem(
+          value: color,
+          child: Row(
+            children: [
+              CircleAvatar(radius: 7, backgroundColor: color),
+              const SizedBox(width: 10),
+              Text(name),
+            ],
+          ),
+        ),
+      const PopupMenuDivider(),
+      const PopupMenuItem(value: 'clear', child: Text('Clear label')),
+    ],
+  );
+
+  if (choice is Color) {
+    notifier.setClipLabel(clip.id, name: clip.name, labelColor: choice);
+  } else if (choice == 'clear') {
+    notifier.setClipLabel(clip.id, name: clip.name);
+  } else if (choice == 'unlink') {
+    notifier.unlinkClip(clip.id);
+  } else if (choice == 'link' && context.mounted) {
+    final other = await showDialog<MediaClip>(
+      context: context,
+      builder: (context) => SimpleDialog(
+        title: Text('Link ${clip.displayName} with'),
+        children: [
+          for (final other in linkable)
+            SimpleDialogOption(
+              onPressed: () => Navigator.of(context).pop(other),
+              child: Text(other.displayName),
+            ),
+        ],
+      ),
+    );
+    if (other != null) notifier.linkClips([clip.id, other.id]);
+  } else if (choice == 'properties' && context.mounted) {
+    await _showClipProperties(context, notifier, clip);
+  } else if (choice == 'rename' && context.mounted) {
+    final name = await _promptClipName(context, clip);
+    if (name != null) {
+      notifier.setClipLabel(
+        clip.id,
+        name: name.isEmpty ? null : name,
+        labelColor: clip.labelColor,
+      );
+    }
+  }
+}
+
+/// Asks for a new clip name. Returns null on cancel, or an empty string to
+/// fall back to the file name.
+Future<String?> _promptClipName(BuildContext context, MediaClip clip) {
+  return showDialog<String>(
+    context: context,
+    builder: (context) => _ClipNameDialog(clip: clip),
+  );
+}
+
+class _ClipNameDialog extends StatefulWidget {
+  final MediaClip clip;
+
+  const _ClipNameDialog({required this.clip});
+
+  @override
+  State<_ClipNameDialog> createState() => _ClipNameDialogState();
+}
+
+class _ClipNameDialogState extends State<_ClipNameDialog> {
+  late final TextEditingController _controller = TextEditingController(
+    text: widget.clip.name ?? '',
+  );
+
+  @override
+  void dispose() {
+    _controller.dispose();
+    super.dispose();
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: const Text('Rename clip'),
+      content: TextField(
+        controller: _controller,
+        autofocus: true,
+        decoration: InputDecoration(hintText: widget.clip.filePath),
+        onSubmitted: (value) => Navigator.of(context).pop(value.trim()),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(_controller.text.trim()),
+          child: const Text('Rename'),
+        ),
+      ],
+    );
+  }
+}
+
+/// Shows every field of [clip]. The name and label can be edited and are
+/// saved through [notifier]; the rest is read-only.
+Future<void> _showClipProperties(
+  BuildContext context,
+  ProjectNotifier notifier,
+  MediaClip clip,
+) async {
+  final edited = await showDialog<({String? name, Color? labelColor})>(
+    context: context,
+    builder: (context) => _ClipPropertiesDialog(clip: clip),
+  );
+  if (edited != null) {
+    notifier.setClipLabel(
+      clip.id,
+      name: edited.name,
+      labelColor: edited.labelColor,
+    );
+  }
+}
+
+/// Pops the edited name (null for the file name) and label color on save.
+class _ClipPropertiesDialog extends StatefulWidget {
+  final MediaClip clip;
+
+  const _ClipPropertiesDialog({required this.clip});
+
+  @override
+  State<_ClipPropertiesDialog> createState() => _ClipPropertiesDialogState();
+}
+
+class _ClipPropertiesDialogState extends State<_ClipPropertiesDialog> {
+  late final TextEditingController _nameController = TextEditingController(
+    text: widget.clip.name ?? '',
+  );
+  late Color? _labelColor = widget.clip.labelColor;
+
+  @override
+  void dispose() {
+    _nameController.dispose();
+    super.dispose();
+  }
+
+  void _save() {
+    final name = _nameController.text.trim();
+    Navigator.of(
+      context,
+    ).pop((name: name.isEmpty ? null : name, labelColor: _labelColor));
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    const fps = VideoUtils.defaultFrameRate;
+    final clip = widget.clip;
+    final current = clip.labelColor;
+    final labels = [
+      ..._labelColors,
+      if (current != null && !_labelColors.any((l) => l.$2 == current))
+        ('Custom', current),
+    ];
+    final rows = <(String, String)>[
+      ('File', clip.filePath),
+      ('Type', clip.type == MediaType.video ? 'Video' : 'Audio'),
+      ('Linked', clip.groupId == null ? 'No' : 'Yes'),
+      (
+        'Opacity',
+        clip.opacity?.isAnimated ?? false
+            ? '${clip.opacity!.points.length} keyframes'
+            : '${(clip.opacityAt(Duration.zero) * 100).round()}%',
+      ),
+      ('In', VideoUtils.formatTimecode(clip.start, fps)),
+      ('Out', VideoUtils.formatTimecode(clip.end, fps)),
+      ('Duration', VideoUtils.formatTimecode(clip.end - clip.start, fps)),
+    ];
+
+    return AlertDialog(
+      title: const Text('Clip properties'),
+      content: Table(
+        columnWidths: const {0: IntrinsicColumnWidth()},
+        defaultVerticalAlignment: TableCellVerticalAlignment.middle,
+        children: [
+          _propertyRow(
+            'Name',
+            TextField(
+              controller: _nameController,
+              decoration: InputDecoration(
+                hintText: clip.filePath,
+                isDense: true,
+              ),
+            ),
+          ),
+          _propertyRow(
+            'Label',
+            DropdownButton<Color>(
+              value: _labelColor,
+              hint: const Text('None'),
+              isDense: true,
+              items: [
+                for (final (name, color) in labels)
+                  DropdownMenuItem(
+                    value: color,
+                    child: Row(
+                      children: [
+                        CircleAvatar(radius: 7, backgroundColor: color),
+                        const SizedBox(width: 10),
+                        Text(name),
+                      ],
                     ),
+                  ),
+              ],
+              onChanged: (color) => setState(() => _labelColor = color),
             ),
           ),
+          for (final (field, value) in rows)
+            _propertyRow(field, SelectableText(value)),
         ],
       ),
+      actions: [
+        if (_labelColor != null)
+          TextButton(
+            onPressed: () => setState(() => _labelColor = null),
+            child: const Text('Clear label'),
+          ),
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        TextButton(onPressed: _save, child: const Text('Save')),
+      ],
     );
   }
 }
+
+TableRow _propertyRow(String field, Widget value) {
+  return TableRow(
+    children: [
+      Padding(
+        padding: const EdgeInsets.only(right: 16, bottom: 6),
+        child: Text(field, style: const TextStyle(fontWeight: FontWeight.w500)),
+      ),
+      value,
+    ],
+  );
+}
@@ -0,0 +1,116 @@
+import 'package:flutter/material.dart';
+
+/// Colors used by the timeline. Register one in [ThemeData.extensions] to
+/// restyle it; [light] matches the editor's original look.
+@immutable
+class TimelineTheme extends ThemeExtension<TimelineTheme> {
+  final Color background;
+  final Color border;
+
+  /// Header icon and empty-state text.
+  final Color mutedForeground;
+
+  /// Header title.
+  final Color labelForeground;
+  final Color clipText;
+  final Color videoFill;
+  final Color videoBorder;
+  final Color videoAccent;
+  final Color audioFill;
+  final Color audioBorder;
+  final Color audioAccent;
+
+  const TimelineTheme({
+    required this.background,
+    required this.border,
+    required this.mutedForeground,
+    required this.labelForeground,
+    required this.clipText,
+    required this.videoFill,
+    required this.videoBorder,
+    required this.videoAccent,
+    required this.audioFill,
+    required this.audioBorder,
+    required this.audioAccent,
+  });
+
+  static final light = TimelineTheme(
+    background: Colors.white,
+    border: const Color(0xFFE5E7EB),
+    mutedForeground: Colors.grey.shade400,
+    labelForeground: Colors.grey.shade700,
+    clipText: Colors.grey.shade800,
+    videoFill: Colors.deepPurple.shade50,
+    videoBorder: Colors.deepPurple.shade100,
+    videoAccent: Colors.deepPurple,
+    audioFill: Colors.teal.shade50,
+    audioBorder: Colors.teal.shade100,
+    audioAccent: Colors.teal,
+  );
+
+  static final dark = TimelineTheme(
+    background: const Color(0xFF18181B),
+    border: const Color(0xFF3F3F46),
+    mutedForeground: Colors.grey.shade600,
+    labelForeground: Colors.grey.shade300,
+    clipText: Colors.grey.shade200,
+    videoFill: const Color(0xFF2E1065),
+    videoBorder: Colors.deepPurple.shade300,
+    videoAccent: Colors.deepPurple.shade200,
+    audioFill: const Color(0xFF042F2E),
+    audioBorder: Colors.teal.shade300,
+    audioAccent: Colors.teal.shade200,
+  );
+
+  /// The timeline theme registered on the ambient [Theme], or [light].
+  static TimelineTheme of(BuildContext context) {
+    return Theme.of(context).extension<TimelineTheme>() ?? light;
+  }
+
+  @override
+  TimelineTheme copyWith({
+    Color? background,
+    Color? border,
+    Color? mutedForeground,
+    Color? labelForeground,
+    Color? clipText,
+    Color? videoFill,
+    Color? videoBorder,
+    Color? videoAccent,
+    Color? audioFill,
+    Color? audioBorder,
+    Color? audioAccent,
+  }) {
+    return TimelineTheme(
+      background: background ?? this.background,
+      border: border ?? this.border,
+      mutedForeground: mutedForeground ?? this.mutedForeground,
+      labelForeground: labelForeground ?? this.labelForeground,
+      clipText: clipText ?? this.clipText,
+      videoFill: videoFill ?? this.videoFill,
+      videoBorder: videoBorder ?? this.videoBorder,
+      videoAccent: videoAccent ?? this.videoAccent,
+      audioFill: audioFill ?? this.audioFill,
+      audioBorder: audioBorder ?? this.audioBorder,
+      audioAccent: audioAccent ?? this.audioAccent,
+    );
+  }
+
+  @override
+  TimelineTheme lerp(TimelineTheme? other, double t) {
+    if (other == null) return this;
+    return TimelineTheme(
+      background: Color.lerp(background, other.background, t)!,
+      border: Color.lerp(border, other.border, t)!,
+      mutedForeground: Color.lerp(mutedForeground, other.mutedForeground, t)!,
+      labelForeground: Color.lerp(labelForeground, other.labelForeground, t)!,
+      clipText: Color.lerp(clipText, other.clipText, t)!,
+      videoFill: Color.lerp(videoFill, other.videoFill, t)!,
+      videoBorder: Color.lerp(videoBorder, other.videoBorder, t)!,
+      videoAccent: Color.lerp(videoAccent, other.videoAccent, t)!,
+      audioFill: Color.lerp(audioFill, other.audioFill, t)!,
+      audioBorder: Color.lerp(audioBorder, other.audioBorder, t)!,
+      audioAccent: Color.lerp(audioAccent, other.audioAccent, t)!,
+    );
+  }
+}
@@ -0,0 +1,184 @@
+use super::error::DinoError;
+use super::video::{open_input, stream_start};
+use ffmpeg::format::sample::Type as SampleType;
+use ffmpeg::format::Sample;
+use ffmpeg::software::resampling;
+use ffmpeg::util::frame::audio::Audio;
+use ffmpeg::ChannelLayout;
+use ffmpeg_next as ffmpeg;
+use ffmpeg_next::Rescale;
+
+/// Decoded audio returned by `extract_audio_samples`.
+pub struct AudioSamples {
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Interleaved samples, `channels` values per frame.
+    pub samples: Vec<f32>,
+}
+
+/// Decodes the best audio stream of `audio_path` between `start_time` and
+/// `end_time` (seconds; `None` reads to the end) and resamples it to
+/// interleaved f32 at `target_rate`, keeping the source channel layout. A
+/// `target_rate` of 0 keeps the source rate. The first sample is always at
+/// `start_time`: if the audio begins later, the gap is filled with silence.
+/// Files without an audio stream are reported as a `Decode` error rather than
+/// returning silence.
+pub fn extract_audio_samples(
+    audio_path: String,
+    start_time: f64,
+    end_time: Option<f64>,
+    target_rate: u32,
+) -> Result<AudioSamples, DinoError> {
+    let mut ictx = open_input(&audio_path, "Failed to open audio")?;
+
+    let input = ictx
+        .streams()
+        .best(ffmpeg::media::Type::Audio)
+        .ok_or_else(|| DinoError::decode("No audio stream found"))?;
+    let stream_index = input.index();
+    let time_base = f64::from(input.time_base());
+    let start_offset = stream_start(&input);
+    let mut decoder = ffmpeg::codec::context::Context::from_parameters(input.parameters())
+        .and_then(|context| context.decoder().audio())
+        .map_err(|e| DinoError::ffmpeg("Failed to get audio decoder", e))?;
+
+    let start = start_time.max(0.0);
+    if start > 0.0 {
+        let ts = (((start + start_offset) * 1000.0) as i64)
+            .rescale((1, 1000), ffmpeg::rescale::TIME_BASE);
+        // Decoding from the top still works if the container can't seek.
+        let _ = ictx.seek(ts, ..ts);
+    }
+
+    let mut resampler: Option<resampling::Context> = None;
+    let mut out = AudioSamples {
+        sample_rate: if target_rate > 0 {
+            target_rate
+        } else {
+            decoder.rate()
+        },
+        channels: 0,
+        samples: Vec::new(),
+    };
+    // Time of the first sample collected, used to align the output to `start`
+    // at the end.
+    let mut first_time: Option<f64> = None;
+    // Where the previous frame ended, for frames that carry no PTS.
+    let mut next_time: Option<f64> = None;
+    let mut frame = Audio::empty();
+    let mut eof = false;
+
+    'decode: loop {
+        while decoder.receive_frame(&mut frame).is_ok() {
+            let rate = f64::from(frame.rate().max(1));
+            let time = frame.timestamp().map_or_else(
+                || next_time.unwrap_or(start),
+                |ts| ts as f64 * time_base - start_offset,
+            );
+            let frame_end = time + frame.samples() as f64 / rate;
+            next_time = Some(frame_end);
+            if frame_end <= start {
+                continue;
+            }
+            if end_time.is_some_and(|end| time >= end) {
+                break 'decode;
+            }
+            first_time.get_or_insert(time);
+
+            if resampler.is_none() {
+                let layout = if frame.channel_layout().is_empty() {
+                    ChannelLayout::default(i32::from(frame.channels()))
+                } else {
+                    frame.channel_layout()
+                };
+                let context = resampling::Context::get(
+                    frame.format(),
+                    layout,
+                    frame.rate(),
+                    Sample::F32(SampleType::Packed),
+                    layout,
+                    out.sample_rate,
+                )
+                .map_err(|e| DinoError::ffmpeg("Failed to create resampler", e))?;
+                out.channels = frame.channels();
+                resampler = Some(context);
+            }
+            let resampler = resampler.as_mut().expect("resampler initialized above");
+            let capacity = frame.samples() * out.sample_rate as usize / rate as usize + 256;
+            let mut resampled = Audio::new(
+                Sample::F32(SampleType::Packed),
+                capacity,
+                resampler.output().channel_layout,
+            );
+            resampler
+                .run(&frame, &mut resampled)
+                .map_err(|e| DinoError::ffmpeg("Failed to resample audio", e))?;
+            append_samples(&resampled, &mut out.samples);
+        }
+        if eof {
+            break;
+        }
+        match ictx.packets().next() {
+            Some((stream, packet)) => {
+                if stream.index() == stream_index {
+                    decoder
+                        .send_packet(&packet)
+                        .map_err(|e| DinoError::decode(format!("Send packet error: {}", e)))?;
+                }
+            }
+            None => {
+                eof = true;
+                let _ = decoder.send_eof();
+            }
+        }
+    }
+
+    // Drain whatever the resampler is still holding back.
+    if let Some(resampler) = resampler.as_mut() {
+        loop {
+            let mut resampled = Audio::new(
+                Sample::F32(SampleType::Packed),
+                4096,
+                resampler.output().channel_layout,
+            );
+            resampler
+                .flush(&mut resampled)
+                .map_err(|e| DinoError::ffmpeg("Failed to resample audio", e))?;
+            if resampled.samples() == 0 {
+                break;
+            }
+            append_samples(&resampled, &mut out.samples);
+        }
+    }
+
+    // Frames rarely line up with the requested range, so cut to it exactly.
+    // Audio that begins after `start` is padded with silence so the first
+    // sample always sits at `start`.
+    let channels = usize::from(out.channels);
+    let rate = f64::from(out.sample_rate);
+    if let Some(first) = first_time {
+        let offset = ((start - first) * rate).round() as i64;
+        if offset >= 0 {
+            out.samples
+                .drain(..(offset as usize * channels).min(out.samples.len()));
+        } else {
+            let padding = (-offset) as usize * channels;
+            out.samples.splice(0..0, std::iter::repeat_n(0.0, padding));
+        }
+    }
+    if let Some(end) = end_time {
+        let keep = ((end - start).max(0.0) * rate).round() as usize;
+        out.samples.truncate(keep * channels);
+    }
+    Ok(out)
+}
+
+/// Appends the samples of a packed f32 frame to `samples`.
+fn append_samples(frame: &Audio, samples: &mut Vec<f32>) {
+    let len = frame.samples() * usize::from(frame.channels()) * 4;
+    samples.extend(
+        frame.data(0)[..len]
+            .chunks_exact(4)
+            .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+    );
+}
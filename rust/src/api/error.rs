@@ -0,0 +1,60 @@
+use ffmpeg_next as ffmpeg;
+use std::fmt;
+
+/// Which part of the pipeline an error came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DinoErrorKind {
+    /// Filesystem access failed (creating output dirs, writing files).
+    Io,
+    /// FFmpeg could not be initialized or refused an operation.
+    Ffmpeg,
+    /// The media opened but its streams could not be decoded.
+    Decode,
+    /// Encoding or writing an image failed.
+    Image,
+    /// The input file does not exist.
+    MissingFile,
+}
+
+/// Error returned across the bridge. Kept as a kind plus message rather than
+/// an enum with payloads so it maps to a plain Dart class.
+#[derive(Debug, Clone)]
+pub struct DinoError {
+    pub kind: DinoErrorKind,
+    pub message: String,
+}
+
+impl DinoError {
+    pub(crate) fn new(kind: DinoErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn ffmpeg(context: &str, err: ffmpeg::Error) -> Self {
+        Self::new(DinoErrorKind::Ffmpeg, format!("{}: {}", context, err))
+    }
+
+    pub(crate) fn io(context: &str, err: std::io::Error) -> Self {
+        Self::new(DinoErrorKind::Io, format!("{}: {}", context, err))
+    }
+
+    pub(crate) fn decode(message: impl Into<String>) -> Self {
+        Self::new(DinoErrorKind::Decode, message)
+    }
+}
+
+impl fmt::Display for DinoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for DinoError {}
+
+impl From<image::ImageError> for DinoError {
+    fn from(err: image::ImageError) -> Self {
+        Self::new(DinoErrorKind::Image, format!("Failed to save PNG: {}", err))
+    }
+}
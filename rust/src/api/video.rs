@@ -1,6 +1,62 @@
+use super::error::{DinoError, DinoErrorKind};
+use ffmpeg::color;
+use ffmpeg::ffi;
+use ffmpeg::format::Pixel;
+use ffmpeg::software::scaling;
+use ffmpeg::util::frame::video::Video;
 use ffmpeg_next as ffmpeg;
+use ffmpeg_next::Rescale;
+use std::collections::hash_map::DefaultHasher;
 use std::fs;
-use std::path::Path;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// Sources kept open between `extract_frame_at` calls, least recently used
+/// first. Scrubbing or stepping through a clip reuses its demuxer and decoder
+/// instead of reopening the file for every frame.
+static OPEN_SOURCES: OnceLock<Mutex<Vec<(String, VideoSource)>>> = OnceLock::new();
+
+const MAX_OPEN_SOURCES: usize = 4;
+
+/// Forward jumps up to this many seconds decode on from the current position
+/// rather than seeking.
+const MAX_DECODE_AHEAD: f64 = 2.0;
+
+/// Codecs the preview decodes reliably. Anything else opens but is worth
+/// transcoding to an intermediate at import.
+const WELL_SUPPORTED_CODECS: &[&str] = &["h264", "hevc", "vp8", "vp9", "av1", "mpeg4", "prores"];
+
+/// User overrides for the YUV matrix and range, applied to every conversion.
+static COLOR_OVERRIDE: Mutex<(ColorMatrix, ColorRange)> =
+    Mutex::new((ColorMatrix::Auto, ColorRange::Auto));
+
+/// YUV to RGB matrix used when converting frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMatrix {
+    /// Follow the stream, falling back to BT.709 for HD and BT.601 below.
+    Auto,
+    Bt601,
+    Bt709,
+    Bt2020,
+}
+
+/// Whether source luma spans 16-235 (limited) or 0-255 (full).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorRange {
+    /// Follow the stream, treating unspecified as limited.
+    Auto,
+    Limited,
+    Full,
+}
+
+/// Forces the matrix and range used when converting frames, for files whose
+/// color metadata is missing or wrong. Takes effect on the next extraction.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_color_override(matrix: ColorMatrix, range: ColorRange) {
+    *COLOR_OVERRIDE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = (matrix, range);
+}
 
 #[flutter_rust_bridge::frb(sync)]
 pub fn extract_frames_to_disk(
@@ -8,68 +64,749 @@ pub fn extract_frames_to_disk(
     output_dir: String,
     every_nth: usize,
     max_frames: usize,
-) -> Result<Vec<String>, String> {
-    ffmpeg::init().map_err(|e| format!("FFmpeg init error: {:?}", e))?;
+) -> Result<Vec<String>, DinoError> {
+    extract_frame_range_to_disk(
+        video_path, output_dir, every_nth, max_frames, None, None, None, None,
+    )
+}
 
-    let mut ictx =
-        ffmpeg::format::input(&video_path).map_err(|e| format!("Failed to open video: {:?}", e))?;
+/// Same as `extract_frames_to_disk`, restricted to `start_time..=end_time` (in
+/// seconds) and optionally scaled. Files are named by the frame's time in
+/// milliseconds, e.g. `frame_00012500.png`. If only one of `out_width`/`out_height` is
+/// given, the other is derived from the source aspect ratio.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn extract_frame_range_to_disk(
+    video_path: String,
+    output_dir: String,
+    every_nth: usize,
+    max_frames: usize,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    out_width: Option<u32>,
+    out_height: Option<u32>,
+) -> Result<Vec<String>, DinoError> {
+    let frames = extract_timed_frames_to_disk(
+        video_path, output_dir, every_nth, max_frames, start_time, end_time, out_width, out_height,
+    )?;
+    Ok(frames.into_iter().map(|(path, _)| path).collect())
+}
 
-    let input = ictx
-        .streams()
-        .best(ffmpeg::media::Type::Video)
-        .ok_or("No video stream found")?;
-    let video_stream_index = input.index();
+/// Same as `extract_frame_range_to_disk`, but pairs each written path with the
+/// frame's presentation time in seconds, taken from its PTS and the stream
+/// time base and counted from the stream's start time.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn extract_timed_frames_to_disk(
+    video_path: String,
+    output_dir: String,
+    every_nth: usize,
+    max_frames: usize,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    out_width: Option<u32>,
+    out_height: Option<u32>,
+) -> Result<Vec<(String, f64)>, DinoError> {
+    let mut source = VideoSource::open(&video_path)?;
+    if let Some(start) = start_time.filter(|t| *t > 0.0) {
+        source.seek(start)?;
+    }
 
-    let mut decoder = input
-        .codec()
-        .decoder()
-        .video()
-        .map_err(|e| format!("Failed to get video decoder: {:?}", e))?;
+    fs::create_dir_all(&output_dir).map_err(|e| DinoError::io("Failed to create output dir", e))?;
 
+    let every_nth = every_nth.max(1);
+    let mut converter = RgbaConverter::new(out_width, out_height);
     let mut frame_index = 0;
-    let mut saved = 0;
-    let mut saved_paths = Vec::new();
-
-    fs::create_dir_all(&output_dir).map_err(|e| format!("Failed to create output dir: {:?}", e))?;
-
-    for (stream, packet) in ictx.packets() {
-        if stream.index() == video_stream_index {
-            decoder
-                .send_packet(&packet)
-                .map_err(|e| format!("Send packet error: {:?}", e))?;
-            let mut frame = ffmpeg::util::frame::video::Video::empty();
-            while decoder.receive_frame(&mut frame).is_ok() {
-                if frame_index % every_nth == 0 && saved < max_frames {
-                    let file_name = format!("{}/frame_{:05}.png", output_dir, frame_index);
-                    save_frame_as_png(&frame, &file_name)
-                        .map_err(|e| format!("Failed to save frame: {:?}", e))?;
-                    saved_paths.push(file_name);
-                    saved += 1;
+    let mut saved = Vec::new();
+
+    while saved.len() < max_frames {
+        let Some((frame, time)) = source.next_frame()? else {
+            break;
+        };
+        // After a seek, decoding restarts at the preceding keyframe.
+        if start_time.is_some_and(|start| time < start) {
+            continue;
+        }
+        if end_time.is_some_and(|end| time > end) {
+            break;
+        }
+
+        if frame_index % every_nth == 0 {
+            let rgba = converter.convert(&frame)?;
+            // Named by presentation time rather than by `frame_index`, which
+            // restarts for every range, so segments extracted into the same
+            // directory don't overwrite each other.
+            let file_name = format!(
+                "{}/frame_{:08}.png",
+                output_dir,
+                (time.max(0.0) * 1000.0).round() as u64
+            );
+            save_frame_as_png(&rgba, &file_name)?;
+            saved.push((file_name, time));
+        }
+        frame_index += 1;
+    }
+    Ok(saved)
+}
+
+/// Writes the frame on screen at `timestamp_secs` to a PNG in the system temp
+/// directory and returns its path. Seeks to the preceding keyframe and decodes
+/// forward from there, so grabbing a frame deep into a file stays cheap. The
+/// height follows the source aspect ratio when `out_width` is set.
+///
+/// Only the latest frame per source and width is kept on disk; the previous
+/// one is deleted once the new one is written. Each file still gets its own
+/// name so image caches keyed by path don't show a stale frame.
+pub fn extract_frame_at(
+    video_path: String,
+    timestamp_secs: f64,
+    out_width: Option<u32>,
+) -> Result<String, DinoError> {
+    let frame = with_open_source(&video_path, |source| {
+        source.frame_at(timestamp_secs.max(0.0))
+    })?
+    .ok_or_else(|| DinoError::decode("No frame found at the requested time"))?;
+    let rgba = RgbaConverter::new(out_width, None).convert(&frame)?;
+
+    let output_dir = std::env::temp_dir().join("dino_frames");
+    fs::create_dir_all(&output_dir).map_err(|e| DinoError::io("Failed to create output dir", e))?;
+
+    let mut hasher = DefaultHasher::new();
+    video_path.hash(&mut hasher);
+    // The width is part of the name so thumbnail and full-size grabs of the
+    // same instant don't overwrite each other.
+    let prefix = format!("frame_{:016x}_", hasher.finish());
+    let suffix = format!("_{}.png", rgba.width());
+    let name = format!(
+        "{}{}{}",
+        prefix,
+        (timestamp_secs * 1000.0).round() as i64,
+        suffix
+    );
+    let file_name = output_dir.join(&name).to_string_lossy().into_owned();
+    save_frame_as_png(&rgba, &file_name)?;
+    remove_stale_frames(&output_dir, &prefix, &suffix, &name);
+    Ok(file_name)
+}
+
+/// Deletes earlier `extract_frame_at` output for the same source and width,
+/// i.e. files named `{prefix}<ms>{suffix}` other than `keep`. Failures are
+/// ignored: a leftover file only costs disk space.
+fn remove_stale_frames(dir: &std::path::Path, prefix: &str, suffix: &str, keep: &str) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else {
+            continue;
+        };
+        let stale = name != keep
+            && name
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+                .is_some_and(|ms| ms.parse::<i64>().is_ok());
+        if stale {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Stream facts reported by `probe_video`.
+pub struct VideoInfo {
+    pub width: u32,
+    pub height: u32,
+    /// Duration in seconds, or 0 when it can't be determined.
+    pub duration: f64,
+    /// Average frame rate, or 0 when unknown.
+    pub frame_rate: f64,
+    /// Whether the stream looks variable frame rate. Frames are always looked
+    /// up by their PTS, so VFR sources don't drift, but `frame_rate` is only
+    /// an average for them and shouldn't be used to step frame by frame.
+    pub is_vfr: bool,
+    /// Whether the first frame decoded. A file can open with sane metadata
+    /// and still fail here, e.g. on an unsupported codec profile.
+    pub is_decodable: bool,
+    /// FFmpeg's short name for the video codec, e.g. `h264`.
+    pub codec_name: String,
+    /// Whether the file should be transcoded to a friendlier intermediate
+    /// before editing: the codec isn't in the well-supported list, or the
+    /// first frame failed to decode.
+    pub needs_transcode: bool,
+}
+
+/// Reads the metadata of the best video stream in `video_path`. With `deep`
+/// set, also decodes the first frame so media that probes fine but won't play
+/// is caught at import; otherwise `is_decodable` only means a decoder opened.
+///
+/// The duration comes from the container, then from the video stream. Only a
+/// `deep` probe falls back to reading every packet, since that touches the
+/// whole file.
+pub fn probe_video(video_path: String, deep: bool) -> Result<VideoInfo, DinoError> {
+    let mut source = VideoSource::open(&video_path)?;
+
+    let stream_duration = source
+        .ictx
+        .stream(source.stream_index)
+        .map_or(0, |stream| stream.duration());
+    let mut duration = match source.ictx.duration() {
+        d if d > 0 => d as f64 * f64::from(ffmpeg::rescale::TIME_BASE),
+        _ if stream_duration > 0 => stream_duration as f64 * source.time_base,
+        _ => 0.0,
+    };
+    let frame_rate = if source.frame_duration > 0.0 {
+        1.0 / source.frame_duration
+    } else {
+        0.0
+    };
+    let is_decodable = !deep || matches!(source.next_frame(), Ok(Some(_)));
+    if deep && duration == 0.0 {
+        duration = source.scan_duration();
+    }
+    let codec_name = source.decoder.id().name().to_string();
+    let needs_transcode = !is_decodable || !WELL_SUPPORTED_CODECS.contains(&codec_name.as_str());
+
+    Ok(VideoInfo {
+        width: source.decoder.width(),
+        height: source.decoder.height(),
+        duration,
+        frame_rate,
+        is_vfr: source.is_vfr,
+        is_decodable,
+        codec_name,
+        needs_transcode,
+    })
+}
+
+/// Decodes frame `index` (0-based, in presentation order) of `video_path` and
+/// converts it to RGBA at the source size, so tests can assert on the pixels
+/// the app would actually show.
+#[cfg(any(test, feature = "testing"))]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn decode_frame_rgba(video_path: &str, index: usize) -> Result<Video, DinoError> {
+    let mut source = VideoSource::open(video_path)?;
+    let mut converter = RgbaConverter::new(None, None);
+    let mut remaining = index;
+    while let Some((frame, _)) = source.next_frame()? {
+        if remaining == 0 {
+            return converter.convert(&frame);
+        }
+        remaining -= 1;
+    }
+    Err(DinoError::decode(format!(
+        "Video has fewer than {} frames",
+        index + 1
+    )))
+}
+
+/// Runs `f` against the cached source for `video_path`, opening it on first
+/// use. A source whose call failed is dropped since its position is unknown.
+fn with_open_source<T>(
+    video_path: &str,
+    f: impl FnOnce(&mut VideoSource) -> Result<T, DinoError>,
+) -> Result<T, DinoError> {
+    let mut sources = OPEN_SOURCES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    let mut source = match sources.iter().position(|(path, _)| path == video_path) {
+        Some(index) => sources.remove(index).1,
+        None => VideoSource::open(video_path)?,
+    };
+    let result = f(&mut source);
+    if result.is_ok() {
+        sources.push((video_path.to_string(), source));
+        if sources.len() > MAX_OPEN_SOURCES {
+            sources.remove(0);
+        }
+    }
+    result
+}
+
+/// Initializes FFmpeg and opens `path` for demuxing, reporting a missing file
+/// as `MissingFile` and anything else under `context`.
+pub(crate) fn open_input(
+    path: &str,
+    context: &str,
+) -> Result<ffmpeg::format::context::Input, DinoError> {
+    ffmpeg::init().map_err(|e| DinoError::ffmpeg("FFmpeg init error", e))?;
+
+    ffmpeg::format::input(path).map_err(|e| match e {
+        ffmpeg::Error::Other {
+            errno: ffmpeg::error::ENOENT,
+        } => DinoError::new(
+            DinoErrorKind::MissingFile,
+            format!("File not found: {}", path),
+        ),
+        e => DinoError::ffmpeg(context, e),
+    })
+}
+
+/// Time of the first timestamp in `stream`, in seconds, or 0 when unknown.
+/// Many files don't start at zero (MPEG-TS usually sits around 1.4 s), so
+/// this is subtracted from every reported time and added back when seeking.
+pub(crate) fn stream_start(stream: &ffmpeg::format::stream::Stream) -> f64 {
+    match stream.start_time() {
+        ffi::AV_NOPTS_VALUE => 0.0,
+        ts => ts as f64 * f64::from(stream.time_base()),
+    }
+}
+
+/// An opened file together with a decoder for its best video stream. Times
+/// are in seconds from the start of the stream.
+struct VideoSource {
+    ictx: ffmpeg::format::context::Input,
+    stream_index: usize,
+    time_base: f64,
+    start_time: f64,
+    frame_duration: f64,
+    is_vfr: bool,
+    decoder: ffmpeg::decoder::Video,
+    last_time: Option<f64>,
+    eof: bool,
+    /// The frame last returned by `frame_at`.
+    shown: Option<(Video, f64)>,
+    /// A frame decoded past the last `frame_at` target, handed out next.
+    pending: Option<(Video, f64)>,
+}
+
+impl VideoSource {
+    fn open(video_path: &str) -> Result<Self, DinoError> {
+        let ictx = open_input(video_path, "Failed to open video")?;
+
+        let input = ictx
+            .streams()
+            .best(ffmpeg::media::Type::Video)
+            .ok_or_else(|| DinoError::decode("No video stream found"))?;
+        let stream_index = input.index();
+        let time_base = f64::from(input.time_base());
+        let start_time = stream_start(&input);
+        let frame_rate = input.avg_frame_rate();
+        let frame_duration = if frame_rate.numerator() > 0 {
+            1.0 / f64::from(frame_rate)
+        } else {
+            0.0
+        };
+        let is_vfr = is_variable_rate(frame_rate, input.rate());
+
+        let decoder = ffmpeg::codec::context::Context::from_parameters(input.parameters())
+            .and_then(|context| context.decoder().video())
+            .map_err(|e| DinoError::ffmpeg("Failed to get video decoder", e))?;
+
+        Ok(Self {
+            ictx,
+            stream_index,
+            time_base,
+            start_time,
+            frame_duration,
+            is_vfr,
+            decoder,
+            last_time: None,
+            eof: false,
+            shown: None,
+            pending: None,
+        })
+    }
+
+    /// Seeks to the keyframe at or before `time` (seconds) and drops anything
+    /// still buffered in the decoder.
+    fn seek(&mut self, time: f64) -> Result<(), DinoError> {
+        let time = time + self.start_time;
+        let ts = ((time * 1000.0) as i64).rescale((1, 1000), ffmpeg::rescale::TIME_BASE);
+        // There is nothing to land on when `time` precedes the first keyframe,
+        // so fall back to the start of the file.
+        if self.ictx.seek(ts, ..ts).is_err() {
+            self.ictx
+                .seek(0, ..)
+                .map_err(|e| DinoError::ffmpeg("Failed to seek", e))?;
+        }
+        self.decoder.flush();
+        self.last_time = None;
+        self.eof = false;
+        self.shown = None;
+        self.pending = None;
+        Ok(())
+    }
+
+    /// Finds where the video stream ends by demuxing the rest of the file.
+    /// Leaves the source at EOF.
+    fn scan_duration(&mut self) -> f64 {
+        let end = self
+            .ictx
+            .packets()
+            .filter(|(stream, _)| stream.index() == self.stream_index)
+            .filter_map(|(_, packet)| Some(packet.pts()? + packet.duration()))
+            .max();
+        end.map_or(0.0, |end| {
+            (end as f64 * self.time_base - self.start_time).max(0.0)
+        })
+    }
+
+    /// Decodes the next frame in presentation order along with its time in
+    /// seconds, or `None` once the stream is exhausted.
+    fn next_frame(&mut self) -> Result<Option<(Video, f64)>, DinoError> {
+        if let Some(pending) = self.pending.take() {
+            return Ok(Some(pending));
+        }
+        let mut frame = Video::empty();
+        loop {
+            if self.decoder.receive_frame(&mut frame).is_ok() {
+                // Frames without a usable PTS are placed one frame after the
+                // previous one.
+                let time = match frame.timestamp() {
+                    Some(ts) => ts as f64 * self.time_base - self.start_time,
+                    None => self.last_time.map_or(0.0, |t| t + self.frame_duration),
+                };
+                self.last_time = Some(time);
+                return Ok(Some((frame, time)));
+            }
+            if self.eof {
+                return Ok(None);
+            }
+            match self.ictx.packets().next() {
+                Some((stream, packet)) => {
+                    if stream.index() == self.stream_index {
+                        self.decoder
+                            .send_packet(&packet)
+                            .map_err(|e| DinoError::decode(format!("Send packet error: {}", e)))?;
+                    }
                 }
-                frame_index += 1;
-                if saved >= max_frames {
-                    break;
+                None => {
+                    // Flush the frames still buffered in the decoder.
+                    self.eof = true;
+                    let _ = self.decoder.send_eof();
                 }
             }
         }
-        if saved >= max_frames {
-            break;
+    }
+
+    /// Returns the frame on screen at `time`: the last one that starts at or
+    /// before it, or the first frame if the stream starts later. Seeks only
+    /// when `time` is behind the last returned frame or too far ahead of it.
+    fn frame_at(&mut self, time: f64) -> Result<Option<Video>, DinoError> {
+        let decode_on = self
+            .shown
+            .as_ref()
+            .is_some_and(|(_, shown)| *shown <= time && time - *shown <= MAX_DECODE_AHEAD);
+        if !decode_on {
+            self.seek(time)?;
         }
+
+        while let Some((frame, frame_time)) = self.next_frame()? {
+            if frame_time > time && self.shown.is_some() {
+                self.pending = Some((frame, frame_time));
+                break;
+            }
+            self.shown = Some((frame, frame_time));
+        }
+        Ok(self.shown.as_ref().map(|(frame, _)| frame.clone()))
+    }
+}
+
+/// Treats a stream as variable frame rate when its average rate strays more
+/// than 1% from the base (`r_frame_rate`) rate. Constant-rate streams report
+/// the same value for both.
+fn is_variable_rate(avg: ffmpeg::Rational, base: ffmpeg::Rational) -> bool {
+    if avg.numerator() <= 0 || base.numerator() <= 0 {
+        return false;
     }
-    Ok(saved_paths)
+    let (avg, base) = (f64::from(avg), f64::from(base));
+    (avg - base).abs() / base > 0.01
 }
 
-// Helper function to save a frame as PNG
-fn save_frame_as_png(frame: &ffmpeg::util::frame::video::Video, path: &str) -> Result<(), String> {
+/// Converts decoded frames to RGBA at a requested output size. The scaler is
+/// created lazily because some codecs only report their pixel format once the
+/// first frame is decoded.
+struct RgbaConverter {
+    out_size: (Option<u32>, Option<u32>),
+    scaler: Option<scaling::Context>,
+}
+
+impl RgbaConverter {
+    fn new(out_width: Option<u32>, out_height: Option<u32>) -> Self {
+        Self {
+            out_size: (out_width.filter(|w| *w > 0), out_height.filter(|h| *h > 0)),
+            scaler: None,
+        }
+    }
+
+    fn convert(&mut self, frame: &Video) -> Result<Video, DinoError> {
+        if self.scaler.is_none() {
+            let (width, height) = target_size(
+                frame.width(),
+                frame.height(),
+                self.out_size.0,
+                self.out_size.1,
+            );
+            let mut scaler = scaling::Context::get(
+                frame.format(),
+                frame.width(),
+                frame.height(),
+                Pixel::RGBA,
+                width,
+                height,
+                scaling::Flags::BILINEAR,
+            )
+            .map_err(|e| DinoError::ffmpeg("Failed to create scaler", e))?;
+            set_source_colorspace(&mut scaler, frame);
+            self.scaler = Some(scaler);
+        }
+
+        let mut rgba = Video::empty();
+        self.scaler
+            .as_mut()
+            .expect("scaler initialized above")
+            .run(frame, &mut rgba)
+            .map_err(|e| DinoError::ffmpeg("Failed to convert frame", e))?;
+        Ok(rgba)
+    }
+}
+
+/// Tells swscale which matrix and range the source uses. Left alone it assumes
+/// BT.601 limited range, which shifts the colors of HD footage and flattens
+/// full-range (e.g. phone or JPEG-style) sources.
+fn set_source_colorspace(scaler: &mut scaling::Context, frame: &Video) {
+    let (matrix, range) = *COLOR_OVERRIDE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    let matrix = match matrix {
+        ColorMatrix::Auto => match frame.color_space() {
+            color::Space::BT709 => ffi::SWS_CS_ITU709,
+            color::Space::FCC => ffi::SWS_CS_FCC,
+            color::Space::BT470BG | color::Space::SMPTE170M => ffi::SWS_CS_ITU601,
+            color::Space::SMPTE240M => ffi::SWS_CS_SMPTE240M,
+            color::Space::BT2020NCL | color::Space::BT2020CL => ffi::SWS_CS_BT2020,
+            _ if frame.height() >= 720 => ffi::SWS_CS_ITU709,
+            _ => ffi::SWS_CS_ITU601,
+        },
+        ColorMatrix::Bt601 => ffi::SWS_CS_ITU601,
+        ColorMatrix::Bt709 => ffi::SWS_CS_ITU709,
+        ColorMatrix::Bt2020 => ffi::SWS_CS_BT2020,
+    };
+    let full_range = match range {
+        ColorRange::Auto => {
+            frame.color_range() == color::Range::JPEG
+                || matches!(
+                    frame.format(),
+                    Pixel::YUVJ420P | Pixel::YUVJ422P | Pixel::YUVJ444P
+                )
+        }
+        ColorRange::Limited => false,
+        ColorRange::Full => true,
+    };
+
+    // RGBA output is always full range. swscale rejects this for RGB inputs,
+    // where there is nothing to configure anyway, so the result is ignored.
+    unsafe {
+        ffi::sws_setColorspaceDetails(
+            scaler.as_mut_ptr(),
+            ffi::sws_getCoefficients(matrix as i32),
+            full_range as i32,
+            ffi::sws_getCoefficients(ffi::SWS_CS_DEFAULT as i32),
+            1,
+            0,
+            1 << 16,
+            1 << 16,
+        );
+    }
+}
+
+/// Resolves the output size, filling in a missing dimension from the source
+/// aspect ratio. The source size is kept as-is when no size is requested.
+fn target_size(src_w: u32, src_h: u32, out_w: Option<u32>, out_h: Option<u32>) -> (u32, u32) {
+    let (width, height) = match (out_w, out_h) {
+        (Some(w), Some(h)) => (w, h),
+        (Some(w), None) => (w, (w as u64 * src_h as u64 / src_w.max(1) as u64) as u32),
+        (None, Some(h)) => ((h as u64 * src_w as u64 / src_h.max(1) as u64) as u32, h),
+        (None, None) => return (src_w.max(1), src_h.max(1)),
+    };
+    even_dims(width, height)
+}
+
+/// Rounds a scaled size down to even numbers. Chroma-subsampled formats such
+/// as yuv420p reject odd dimensions, so keeping resized output even lets the
+/// same sizes be reused when encoding.
+fn even_dims(width: u32, height: u32) -> (u32, u32) {
+    ((width & !1).max(2), (height & !1).max(2))
+}
+
+// Helper function to save an RGBA frame as PNG
+fn save_frame_as_png(frame: &Video, path: &str) -> Result<(), DinoError> {
     use image::{ImageBuffer, Rgba};
     let width = frame.width();
     let height = frame.height();
-    let data = frame.data(0);
-
-    // You may need to convert pixel format here for real-world videos!
-    let buffer = ImageBuffer::<Rgba<u8>, _>::from_raw(width, height, data.to_vec())
-        .ok_or("Failed to create image buffer")?;
-    buffer
-        .save(path)
-        .map_err(|e| format!("Failed to save PNG: {:?}", e))
+
+    // Rows may be padded, so copy out exactly `width` pixels per line.
+    let row_len = width as usize * 4;
+    let mut data = Vec::with_capacity(row_len * height as usize);
+    for row in frame.data(0).chunks(frame.stride(0)).take(height as usize) {
+        data.extend_from_slice(&row[..row_len]);
+    }
+
+    let buffer = ImageBuffer::<Rgba<u8>, _>::from_raw(width, height, data)
+        .ok_or_else(|| DinoError::new(DinoErrorKind::Image, "Failed to create image buffer"))?;
+    buffer.save(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 16x16 yuv420p frame filled with one color.
+    fn yuv_frame(y: u8, u: u8, v: u8, space: color::Space, range: color::Range) -> Video {
+        let mut frame = Video::new(Pixel::YUV420P, 16, 16);
+        frame.set_color_space(space);
+        frame.set_color_range(range);
+        for (plane, value) in [y, u, v].into_iter().enumerate() {
+            frame.data_mut(plane).fill(value);
+        }
+        frame
+    }
+
+    fn center_pixel(frame: &Video) -> [u8; 4] {
+        let offset = 8 * frame.stride(0) + 8 * 4;
+        frame.data(0)[offset..offset + 4].try_into().unwrap()
+    }
+
+    fn assert_close(actual: [u8; 4], expected: [u8; 4]) {
+        let close = actual.iter().zip(expected).all(|(a, e)| a.abs_diff(e) <= 3);
+        assert!(close, "got {:?}, expected {:?}", actual, expected);
+    }
+
+    fn convert(frame: &Video) -> [u8; 4] {
+        ffmpeg::init().unwrap();
+        let rgba = RgbaConverter::new(None, None).convert(frame).unwrap();
+        center_pixel(&rgba)
+    }
+
+    #[test]
+    fn limited_range_maps_to_full_rgb() {
+        let white = yuv_frame(235, 128, 128, color::Space::BT709, color::Range::MPEG);
+        let black = yuv_frame(16, 128, 128, color::Space::BT709, color::Range::MPEG);
+        assert_close(convert(&white), [255, 255, 255, 255]);
+        assert_close(convert(&black), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn full_range_is_not_stretched() {
+        let dark = yuv_frame(16, 128, 128, color::Space::BT709, color::Range::JPEG);
+        assert_close(convert(&dark), [16, 16, 16, 255]);
+    }
+
+    #[test]
+    fn bt709_red_converts_without_a_cast() {
+        let red = yuv_frame(63, 102, 240, color::Space::BT709, color::Range::MPEG);
+        assert_close(convert(&red), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn scaled_sizes_are_even_and_keep_aspect() {
+        assert_eq!(target_size(1920, 1080, Some(641), None), (640, 360));
+        assert_eq!(target_size(1081, 1921, None, None), (1081, 1921));
+    }
+
+    #[test]
+    fn detects_variable_frame_rate() {
+        let ntsc = ffmpeg::Rational::new(30000, 1001);
+        assert!(!is_variable_rate(ntsc, ntsc));
+        assert!(is_variable_rate(
+            ffmpeg::Rational::new(2400, 100),
+            ffmpeg::Rational::new(60, 1)
+        ));
+    }
+
+    /// Writes `frames` to a 25 fps rawvideo NUT file at `path`.
+    fn write_video(path: &std::path::Path, frames: Vec<Video>) {
+        use ffmpeg::{codec, encoder, format};
+
+        ffmpeg::init().unwrap();
+        let mut octx = format::output(path).unwrap();
+        let codec = encoder::find(codec::Id::RAWVIDEO).unwrap();
+        let mut ost = octx.add_stream(codec).unwrap();
+        let mut encoder = codec::context::Context::new_with_codec(codec)
+            .encoder()
+            .video()
+            .unwrap();
+        encoder.set_width(16);
+        encoder.set_height(16);
+        encoder.set_format(Pixel::YUV420P);
+        encoder.set_colorspace(color::Space::BT709);
+        encoder.set_color_range(color::Range::MPEG);
+        encoder.set_time_base((1, 25));
+        encoder.set_frame_rate(Some((25, 1)));
+        let mut encoder = encoder.open_as(codec).unwrap();
+        ost.set_parameters(&encoder);
+        ost.set_time_base((1, 25));
+        octx.write_header().unwrap();
+        let stream_base = octx.stream(0).unwrap().time_base();
+
+        let mut packet = ffmpeg::Packet::empty();
+        let mut write_packets =
+            |encoder: &mut encoder::Video, octx: &mut format::context::Output| {
+                while encoder.receive_packet(&mut packet).is_ok() {
+                    packet.set_stream(0);
+                    packet.rescale_ts((1, 25), stream_base);
+                    packet.write_interleaved(octx).unwrap();
+                }
+            };
+        for (pts, mut frame) in frames.into_iter().enumerate() {
+            frame.set_pts(Some(pts as i64));
+            encoder.send_frame(&frame).unwrap();
+            write_packets(&mut encoder, &mut octx);
+        }
+        encoder.send_eof().unwrap();
+        write_packets(&mut encoder, &mut octx);
+        octx.write_trailer().unwrap();
+    }
+
+    #[test]
+    fn decodes_frames_from_a_file() {
+        let path = std::env::temp_dir().join(format!("dino_decode_{}.nut", std::process::id()));
+        let frames =
+            [235, 126, 16].map(|y| yuv_frame(y, 128, 128, color::Space::BT709, color::Range::MPEG));
+        write_video(&path, frames.into());
+
+        let video_path = path.to_str().unwrap();
+        let pixel = |index| center_pixel(&decode_frame_rgba(video_path, index).unwrap());
+        assert_close(pixel(0), [255, 255, 255, 255]);
+        assert_close(pixel(1), [128, 128, 128, 255]);
+        assert_close(pixel(2), [0, 0, 0, 255]);
+        assert!(decode_frame_rgba(video_path, 3).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn only_the_latest_frame_per_source_and_width_is_kept() {
+        let dir = std::env::temp_dir().join(format!("dino_stale_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "frame_a_100_320.png",
+            "frame_a_200_320.png",
+            "frame_a_100_640.png",
+            "frame_b_100_320.png",
+        ] {
+            fs::write(dir.join(name), b"").unwrap();
+        }
+
+        remove_stale_frames(&dir, "frame_a_", "_320.png", "frame_a_200_320.png");
+
+        let mut left: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        left.sort();
+        assert_eq!(
+            left,
+            [
+                "frame_a_100_640.png",
+                "frame_a_200_320.png",
+                "frame_b_100_320.png"
+            ]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,43 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:dino/models/keyframes.dart';
+
+double _lerp(double a, double b, double t) => a + (b - a) * t;
+
+void main() {
+  group('Keyframes', () {
+    final fade = Keyframes<double>([
+      (const Duration(seconds: 2), 0.0),
+      (Duration.zero, 1.0),
+    ], lerp: _lerp);
+
+    test('sorts points and interpolates linearly between them', () {
+      expect(fade.points.first.$1, Duration.zero);
+      expect(fade.valueAt(const Duration(seconds: 1)), closeTo(0.5, 1e-9));
+      expect(
+        fade.valueAt(const Duration(milliseconds: 500)),
+        closeTo(0.75, 1e-9),
+      );
+    });
+
+    test('holds the end values outside the keyed range', () {
+      expect(fade.valueAt(const Duration(seconds: -1)), 1.0);
+      expect(fade.valueAt(const Duration(seconds: 10)), 0.0);
+    });
+
+    test('withPoint replaces a point at the same time', () {
+      final edited = fade.withPoint(const Duration(seconds: 2), 0.5);
+
+      expect(edited.points, hasLength(2));
+      expect(edited.valueAt(const Duration(seconds: 2)), 0.5);
+    });
+
+    test('withoutPoint keeps the last remaining point', () {
+      final single = Keyframes.constant(0.3, lerp: _lerp);
+
+      expect(single.isAnimated, isFalse);
+      expect(single.withoutPoint(Duration.zero).valueAt(Duration.zero), 0.3);
+      expect(fade.withoutPoint(Duration.zero).isAnimated, isFalse);
+    });
+  });
+}
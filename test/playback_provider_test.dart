@@ -0,0 +1,38 @@
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:dino/models/playback_provider.dart';
+
+void main() {
+  group('PlaybackNotifier speed', () {
+    late ProviderContainer container;
+
+    setUp(() => container = ProviderContainer());
+    tearDown(() => container.dispose());
+
+    test('setSpeed clamps to the supported range', () {
+      final notifier = container.read(playbackProvider.notifier);
+
+      notifier.setSpeed(100);
+      expect(container.read(playbackProvider).speed, PlaybackNotifier.maxSpeed);
+
+      notifier.setSpeed(0);
+      expect(container.read(playbackProvider).speed, PlaybackNotifier.minSpeed);
+    });
+
+    test('faster and slower step through the presets', () {
+      final notifier = container.read(playbackProvider.notifier);
+
+      notifier.faster();
+      expect(container.read(playbackProvider).speed, 2.0);
+
+      notifier.setSpeed(1.5);
+      notifier.slower();
+      expect(container.read(playbackProvider).speed, 1.0);
+
+      notifier.setSpeed(0.25);
+      notifier.slower();
+      expect(container.read(playbackProvider).speed, 0.25);
+    });
+  });
+}
@@ -0,0 +1,259 @@
+import 'dart:ui' show Color;
+
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:dino/models/keyframes.dart';
+import 'package:dino/models/project_provider.dart';
+
+void main() {
+  group('ProjectNotifier.addClip', () {
+    late ProviderContainer container;
+
+    setUp(() => container = ProviderContainer());
+    tearDown(() => container.dispose());
+
+    test('clamps a degenerate video clip to a sane range', () {
+      container
+          .read(projectProvider.notifier)
+          .addClip(
+            MediaClip(
+              id: 'v',
+              filePath: 'video.mp4',
+              start: const Duration(seconds: -3),
+              end: const Duration(seconds: -3),
+              type: MediaType.video,
+            ),
+          );
+
+      final clip = container.read(projectProvider).clips.single;
+      expect(clip.start, Duration.zero);
+      expect(clip.end - clip.start, ProjectNotifier.minClipDuration);
+    });
+
+    test('keeps the length of a clip dropped before zero', () {
+      container
+          .read(projectProvider.notifier)
+          .addClip(
+            MediaClip(
+              id: 'v',
+              filePath: 'video.mp4',
+              start: const Duration(seconds: -2),
+              end: const Duration(seconds: 3),
+              type: MediaType.video,
+            ),
+          );
+
+      final clip = container.read(projectProvider).clips.single;
+      expect(clip.start, Duration.zero);
+      expect(clip.end, const Duration(seconds: 5));
+    });
+
+    test('skips audio clips without a positive duration', () {
+      container
+          .read(projectProvider.notifier)
+          .addClip(
+            MediaClip(
+              id: 'a',
+              filePath: 'audio.mp3',
+              start: const Duration(seconds: 4),
+              end: const Duration(seconds: 4),
+              type: MediaType.audio,
+            ),
+          );
+
+      expect(container.read(projectProvider).clips, isEmpty);
+    });
+  });
+
+  test('ProjectState.duration is the latest clip end', () {
+    final state = ProjectState(
+      title: 'Test',
+      clips: [
+        MediaClip(
+          id: 'v',
+          filePath: 'video.mp4',
+          start: Duration.zero,
+          end: const Duration(seconds: 8),
+          type: MediaType.video,
+        ),
+        MediaClip(
+          id: 'a',
+          filePath: 'audio.mp3',
+          start: const Duration(seconds: 2),
+          end: const Duration(seconds: 5),
+          type: MediaType.audio,
+        ),
+      ],
+    );
+
+    expect(state.duration, const Duration(seconds: 8));
+    expect(ProjectState(title: 'Empty', clips: []).duration, Duration.zero);
+  });
+
+  test('MediaClip.copyWith keeps fields unless told to clear them', () {
+    final clip = MediaClip(
+      id: 'v',
+      filePath: 'video.mp4',
+      start: Duration.zero,
+      end: const Duration(seconds: 4),
+      type: MediaType.video,
+      name: 'Intro',
+      groupId: 'g',
+    );
+
+    final moved = clip.copyWith(start: const Duration(seconds: 1));
+    expect(moved.start, const Duration(seconds: 1));
+    expect(moved.name, 'Intro');
+    expect(moved.groupId, 'g');
+
+    final cleared = clip.copyWith(clearName: true, clearGroupId: true);
+    expect(cleared.name, isNull);
+    expect(cleared.groupId, isNull);
+    expect(cleared.end, clip.end);
+  });
+
+  group('ProjectNotifier.setClipLabel', () {
+    late ProviderContainer container;
+
+    setUp(() => container = ProviderContainer());
+    tearDown(() => container.dispose());
+
+    test('clearing the color keeps the clip name', () {
+      final notifier = container.read(projectProvider.notifier);
+      notifier.addClip(
+        MediaClip(
+          id: 'v',
+          filePath: 'video.mp4',
+          start: Duration.zero,
+          end: const Duration(seconds: 4),
+          type: MediaType.video,
+        ),
+      );
+      notifier.setClipLabel(
+        'v',
+        name: 'Intro',
+        labelColor: const Color(0xFFEF4444),
+      );
+
+      notifier.setClipLabel('v', name: 'Intro');
+
+      final clip = container.read(projectProvider).clips.single;
+      expect(clip.name, 'Intro');
+      expect(clip.labelColor, isNull);
+    });
+  });
+
+  group('ProjectNotifier.setClipOpacity', () {
+    late ProviderContainer container;
+
+    setUp(() => container = ProviderContainer());
+    tearDown(() => container.dispose());
+
+    test('sets keyframes and null makes the clip opaque again', () {
+      final notifier = container.read(projectProvider.notifier);
+      notifier.addClip(
+        MediaClip(
+          id: 'v',
+          filePath: 'video.mp4',
+          start: Duration.zero,
+          end: const Duration(seconds: 4),
+          type: MediaType.video,
+          name: 'Intro',
+        ),
+      );
+      final fade = Keyframes<double>([
+        (Duration.zero, 1.0),
+        (const Duration(seconds: 2), 0.0),
+      ], lerp: (a, b, t) => a + (b - a) * t);
+
+      notifier.setClipOpacity('v', fade);
+      var clip = container.read(projectProvider).clips.single;
+      expect(clip.opacityAt(const Duration(seconds: 1)), closeTo(0.5, 1e-9));
+      expect(clip.name, 'Intro');
+
+      notifier.setClipOpacity('v', null);
+      clip = container.read(projectProvider).clips.single;
+      expect(clip.opacity, isNull);
+      expect(clip.opacityAt(const Duration(seconds: 1)), 1.0);
+    });
+  });
+
+  group('ProjectNotifier dirty tracking', () {
+    late ProviderContainer container;
+
+    setUp(() => container = ProviderContainer());
+    tearDown(() => container.dispose());
+
+    test('edits mark the project dirty and newProject resets it', () {
+      final notifier = container.read(projectProvider.notifier);
+      expect(container.read(projectProvider).isDirty, isFalse);
+
+      notifier.setTitle('Trailer');
+      expect(container.read(projectProvider).isDirty, isTrue);
+
+      notifier.newProject();
+      final project = container.read(projectProvider);
+      expect(project.isDirty, isFalse);
+      expect(project.title, 'Untitled Project');
+      expect(project.clips, isEmpty);
+    });
+
+    test('setting the current title is not an edit', () {
+      container.read(projectProvider.notifier).setTitle('Untitled Project');
+
+      expect(container.read(projectProvider).isDirty, isFalse);
+    });
+  });
+
+  group('ProjectNotifier clip links', () {
+    late ProviderContainer container;
+
+    setUp(() {
+      container = ProviderContainer();
+      final notifier = container.read(projectProvider.notifier);
+      for (final (id, type) in [
+        ('v', MediaType.video),
+        ('a', MediaType.audio),
+        ('b', MediaType.audio),
+      ]) {
+        notifier.addClip(
+          MediaClip(
+            id: id,
+            filePath: '$id.media',
+            start: Duration.zero,
+            end: const Duration(seconds: 4),
+            type: type,
+          ),
+        );
+      }
+      notifier.linkClips(['v', 'a']);
+    });
+    tearDown(() => container.dispose());
+
+    test('removing a linked clip removes its partner', () {
+      container.read(projectProvider.notifier).removeClip('a');
+
+      final ids = container.read(projectProvider).clips.map((c) => c.id);
+      expect(ids, ['b']);
+    });
+
+    test('linking into a group merges it', () {
+      container.read(projectProvider.notifier).linkClips(['b', 'v']);
+
+      final groups = container.read(projectProvider).clips.map(
+        (c) => c.groupId,
+      );
+      expect(groups, ['v', 'v', 'v']);
+    });
+
+    test('unlinked clips are removed on their own', () {
+      final notifier = container.read(projectProvider.notifier);
+      notifier.unlinkClip('v');
+      notifier.removeClip('v');
+
+      final ids = container.read(projectProvider).clips.map((c) => c.id);
+      expect(ids, ['a', 'b']);
+    });
+  });
+}
@@ -0,0 +1,40 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:dino/utils/video_utils.dart';
+
+void main() {
+  group('VideoUtils.parseTimecode', () {
+    test('round-trips formatTimecode', () {
+      const position = Duration(hours: 1, minutes: 2, seconds: 3);
+      final timecode = VideoUtils.formatTimecode(
+        position + const Duration(milliseconds: 500),
+        30,
+      );
+
+      expect(timecode, '01:02:03:15');
+      expect(
+        VideoUtils.formatTimecode(VideoUtils.parseTimecode(timecode, 30)!, 30),
+        timecode,
+      );
+    });
+
+    test('accepts seconds and MM:SS', () {
+      expect(
+        VideoUtils.parseTimecode('12.5', 30),
+        const Duration(milliseconds: 12500),
+      );
+      expect(
+        VideoUtils.parseTimecode('1:30', 30),
+        const Duration(minutes: 1, seconds: 30),
+      );
+    });
+
+    test('rejects malformed input', () {
+      expect(VideoUtils.parseTimecode('', 30), isNull);
+      expect(VideoUtils.parseTimecode('-4', 30), isNull);
+      expect(VideoUtils.parseTimecode('00:61:00', 30), isNull);
+      expect(VideoUtils.parseTimecode('00:00:01:30', 30), isNull);
+      expect(VideoUtils.parseTimecode('a:b', 30), isNull);
+    });
+  });
+}